@@ -1,9 +1,27 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, Mutex, oneshot};
 use futures::executor::block_on;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::HashMap;
+use std::sync::mpsc as std_mpsc;
+use std::sync::atomic::AtomicU64;
+use std::time::Duration;
+use ringbuf::{HeapRb, HeapProd, HeapCons, traits::{Producer, Consumer, Split, Observer}};
+
+// 30 seconds of stereo audio at 44.1kHz; the callback never allocates.
+const RING_BUFFER_CAPACITY: usize = 44100 * 30 * 2;
+
+// Per-source staging buffer for aggregate (mic + system) capture; small enough
+// that a stalled mixer loop can't grow memory unbounded, large enough to
+// absorb the jitter between two independently clocked devices.
+const AGGREGATE_SOURCE_CAPACITY: usize = 44100 * 2;
+const AGGREGATE_MIX_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Number of samples cross-faded at a drift-correction splice point - short
+/// enough to be inaudible, long enough to hide the discontinuity.
+const JITTER_CROSSFADE_SAMPLES: usize = 32;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AudioDevice {
@@ -12,6 +30,18 @@ pub struct AudioDevice {
     pub is_system: bool,
     pub channels: u16,
     pub sample_rate: u32,
+    /// Backend that enumerated this device, e.g. `"Wasapi"` or `"Alsa"` - see
+    /// [`AudioCapture::list_hosts`].
+    pub host_id: String,
+}
+
+/// One audio backend cpal can drive on this platform (WASAPI/ASIO on
+/// Windows, CoreAudio on macOS, ALSA/PulseAudio/JACK on Linux).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HostInfo {
+    pub id: String,
+    pub name: String,
+    pub is_default: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -21,33 +51,331 @@ pub struct AudioConfig {
     pub buffer_size: usize,
 }
 
-pub struct AudioCapture {
+/// Tunables for the adaptive jitter-buffer compensation loop (modeled on
+/// ALVR's audio buffering): how much queued audio to aim for, and how often
+/// to re-check the fill level.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct AudioBufferingConfig {
+    pub target_latency_ms: u32,
+    pub batch_ms: u32,
+}
+
+impl Default for AudioBufferingConfig {
+    fn default() -> Self {
+        Self {
+            target_latency_ms: 200,
+            batch_ms: 20,
+        }
+    }
+}
+
+/// Jitter-buffer health, exposed so callers can monitor capture quality over
+/// a long recording instead of just finding out it clicked afterwards.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct JitterBufferStats {
+    /// Exponential moving average of the shared buffer's fill level, in samples.
+    pub avg_fill_samples: f64,
+    /// Number of times a batch has been dropped or duplicated to correct drift.
+    pub drift_corrections: u64,
+}
+
+/// Commands accepted by the stream-owning worker thread. `cpal::Stream` is
+/// `!Send`, so every stream must be created, played and dropped on the same
+/// OS thread - this channel is the only way the rest of the app talks to it.
+enum StreamCommand {
+    StartCapture {
+        device_id: String,
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+    StopDevice {
+        device_id: String,
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+    StopAll {
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+    StartAggregate {
+        mic_id: String,
+        system_id: String,
+        mic_producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+        system_producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+        mic_last_batch_at: Arc<AtomicU64>,
+        system_last_batch_at: Arc<AtomicU64>,
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+}
+
+/// A snapshot of recording state answered by `AudioCaptureRequest::QueryStatus` -
+/// bundles the bits that otherwise would cost a separate actor round-trip
+/// each (buffer fill, active device count) into one.
+#[derive(Debug, Clone)]
+pub struct AudioCaptureStatus {
+    pub is_recording: bool,
+    pub active_devices: Vec<String>,
+    pub buffer_size: usize,
+}
+
+/// Requests accepted by the capture actor spawned in
+/// `AudioCapture::new_with_host`. The actor owns the cpal host, the shared
+/// ring buffer's producer/consumer, the resampler and the jitter-compensation
+/// task - every `AudioCapture` method is just a sender on this channel, so
+/// `main.rs` holds it as plain `Arc<AudioCapture>` Tauri state with no lock
+/// at all, instead of holding a lock for the duration of a resample or a
+/// device enumeration the way the old single-struct-behind-one-Mutex design
+/// required.
+enum AudioCaptureRequest {
+    StartCapture { device_id: String, reply: oneshot::Sender<Result<(), String>> },
+    StartAggregate { mic_id: String, system_id: String, reply: oneshot::Sender<Result<(), String>> },
+    StopDevice { device_id: String, reply: oneshot::Sender<Result<(), String>> },
+    StopAll { reply: oneshot::Sender<Result<(), String>> },
+    GetChunk { max_samples: Option<usize>, reply: oneshot::Sender<Vec<f32>> },
+    GetResampled { target_rate: u32, target_channels: u16, reply: oneshot::Sender<Vec<f32>> },
+    QueryStatus { reply: oneshot::Sender<AudioCaptureStatus> },
+    EnumerateDevices { reply: oneshot::Sender<Result<Vec<AudioDevice>, String>> },
+    SetHost { host_id: cpal::HostId, reply: oneshot::Sender<Result<(), String>> },
+    SetBufferingConfig { config: AudioBufferingConfig },
+    GetBufferingStats { reply: oneshot::Sender<JitterBufferStats> },
+}
+
+/// Real capture state, owned exclusively by the actor task spawned in
+/// `AudioCapture::new_with_host` - nothing outside that task ever touches
+/// these fields directly, which is what lets `AudioCapture`'s own methods
+/// avoid holding a lock across an `.await` the way the previous
+/// `Arc<Mutex<AudioCapture>>`-wrapped struct did.
+struct AudioCaptureActorState {
     host: cpal::Host,
-    audio_data: Arc<Mutex<Vec<f32>>>,
+    host_id: cpal::HostId,
+    // Producer side is shared because several device callbacks can push concurrently;
+    // the lock is a plain (non-async) std Mutex so a contended push never parks the
+    // real-time audio thread - it just drops the sample and moves on.
+    producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+    consumer: Arc<Mutex<HeapCons<f32>>>,
     is_recording: Arc<AtomicBool>,
     active_devices: Arc<Mutex<Vec<String>>>,
     sample_rate: u32,
     channels: u16,
+    stream_commands: std_mpsc::Sender<StreamCommand>,
+    aggregate_mixer: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    resampler: StreamingResampler,
+    buffering_config: AudioBufferingConfig,
+    jitter_stats: Arc<Mutex<JitterBufferStats>>,
+    jitter_task: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
 }
 
-impl AudioCapture {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let host = cpal::default_host();
+impl AudioCaptureActorState {
+    fn new_with_host(host_id: cpal::HostId) -> Result<Self, Box<dyn std::error::Error>> {
+        let host = cpal::host_from_id(host_id)?;
+        let rb = HeapRb::<f32>::new(RING_BUFFER_CAPACITY);
+        let (producer, consumer) = rb.split();
+
+        let producer_for_worker = Arc::new(std::sync::Mutex::new(producer));
+        let is_recording = Arc::new(AtomicBool::new(false));
+        let active_devices = Arc::new(Mutex::new(Vec::new()));
+
+        let stream_commands = spawn_stream_worker(
+            host_id,
+            producer_for_worker.clone(),
+            is_recording.clone(),
+            active_devices.clone(),
+        );
+
         Ok(Self {
             host,
-            audio_data: Arc::new(Mutex::new(Vec::new())),
-            is_recording: Arc::new(AtomicBool::new(false)),
-            active_devices: Arc::new(Mutex::new(Vec::new())),
+            host_id,
+            producer: producer_for_worker,
+            consumer: Arc::new(Mutex::new(consumer)),
+            is_recording,
+            active_devices,
             sample_rate: 44100,
             channels: 2,
+            stream_commands,
+            aggregate_mixer: Arc::new(Mutex::new(None)),
+            resampler: StreamingResampler::new(),
+            buffering_config: AudioBufferingConfig::default(),
+            jitter_stats: Arc::new(Mutex::new(JitterBufferStats::default())),
+            jitter_task: Arc::new(Mutex::new(None)),
         })
     }
 
-    pub fn enumerate_devices(&self) -> Result<Vec<AudioDevice>, Box<dyn std::error::Error>> {
+    /// Start the drift-compensation loop if it isn't already running. It
+    /// monitors the shared output buffer for the lifetime of the capture
+    /// session, independent of how many/which devices feed it.
+    async fn ensure_jitter_compensation_running(&self) {
+        let mut task = self.jitter_task.lock().await;
+        if task.is_some() {
+            return;
+        }
+        *task = Some(tokio::spawn(jitter_compensation_loop(
+            self.consumer.clone(),
+            self.producer.clone(),
+            self.sample_rate,
+            self.channels,
+            self.buffering_config,
+            self.jitter_stats.clone(),
+        )));
+    }
+
+    async fn start_capture(&mut self, device_id: String) -> Result<(), String> {
+        println!("🎵 Starting audio capture for device: {}", device_id);
+
+        {
+            let active_devices = self.active_devices.lock().await;
+            if active_devices.contains(&device_id) {
+                println!("⚠️ Device {} already capturing", device_id);
+                return Ok(());
+            }
+        }
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.stream_commands
+            .send(StreamCommand::StartCapture { device_id: device_id.clone(), reply: reply_tx })
+            .map_err(|_| "Audio worker thread is gone".to_string())?;
+        reply_rx.await.map_err(|_| "Audio worker thread dropped the reply".to_string())??;
+
+        {
+            let mut devices = self.active_devices.lock().await;
+            devices.push(device_id.clone());
+        }
+
+        self.is_recording.store(true, Ordering::Relaxed);
+        self.ensure_jitter_compensation_running().await;
+
+        println!("✅ Audio capture started for device: {}", device_id);
+        Ok(())
+    }
+
+    /// Open a microphone and a system-audio (loopback) device together and mix
+    /// them sample-for-sample into the shared buffer, instead of letting two
+    /// unaligned streams interleave into it. Each device still gets its own
+    /// `cpal::Stream` on the worker thread; what's new is that their callbacks
+    /// land in private per-source staging buffers and a mixer task sums the
+    /// two at a fixed cadence, acting as a small jitter buffer that absorbs
+    /// the clock drift between two independently-clocked devices.
+    async fn start_aggregate_capture(&mut self, mic_id: String, system_id: String) -> Result<(), String> {
+        println!("🎚️ Starting aggregate capture: mic={} system={}", mic_id, system_id);
+
+        let mic_rb = HeapRb::<f32>::new(AGGREGATE_SOURCE_CAPACITY);
+        let (mic_producer, mic_consumer) = mic_rb.split();
+        let sys_rb = HeapRb::<f32>::new(AGGREGATE_SOURCE_CAPACITY);
+        let (system_producer, system_consumer) = sys_rb.split();
+
+        let mic_last_batch_at = Arc::new(AtomicU64::new(0));
+        let system_last_batch_at = Arc::new(AtomicU64::new(0));
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.stream_commands
+            .send(StreamCommand::StartAggregate {
+                mic_id: mic_id.clone(),
+                system_id: system_id.clone(),
+                mic_producer: Arc::new(std::sync::Mutex::new(mic_producer)),
+                system_producer: Arc::new(std::sync::Mutex::new(system_producer)),
+                mic_last_batch_at: mic_last_batch_at.clone(),
+                system_last_batch_at: system_last_batch_at.clone(),
+                reply: reply_tx,
+            })
+            .map_err(|_| "Audio worker thread is gone".to_string())?;
+        reply_rx.await.map_err(|_| "Audio worker thread dropped the reply".to_string())??;
+
+        {
+            let mut devices = self.active_devices.lock().await;
+            devices.push(mic_id.clone());
+            devices.push(system_id.clone());
+        }
+        self.is_recording.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.aggregate_mixer.lock().await.take() {
+            handle.abort();
+        }
+
+        let main_producer = self.producer.clone();
+        let handle = tokio::spawn(mix_aggregate_loop(
+            mic_consumer,
+            system_consumer,
+            main_producer,
+            mic_last_batch_at,
+            system_last_batch_at,
+        ));
+        *self.aggregate_mixer.lock().await = Some(handle);
+        self.ensure_jitter_compensation_running().await;
+
+        println!("✅ Aggregate capture started");
+        Ok(())
+    }
+
+    async fn stop_all(&mut self) -> Result<(), String> {
+        println!("🛑 Stopping all audio capture streams");
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.stream_commands
+            .send(StreamCommand::StopAll { reply: reply_tx })
+            .map_err(|_| "Audio worker thread is gone".to_string())?;
+        reply_rx.await.map_err(|_| "Audio worker thread dropped the reply".to_string())??;
+
+        if let Some(handle) = self.aggregate_mixer.lock().await.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.jitter_task.lock().await.take() {
+            handle.abort();
+        }
+        *self.jitter_stats.lock().await = JitterBufferStats::default();
+
+        self.is_recording.store(false, Ordering::Relaxed);
+        self.active_devices.lock().await.clear();
+        self.consumer.lock().await.clear();
+
+        println!("✅ All audio capture stopped");
+        Ok(())
+    }
+
+    async fn stop_device_capture(&mut self, device_id: &str) -> Result<(), String> {
+        println!("🛑 Stopping stream for device: {}", device_id);
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.stream_commands
+            .send(StreamCommand::StopDevice { device_id: device_id.to_string(), reply: reply_tx })
+            .map_err(|_| "Audio worker thread is gone".to_string())?;
+        reply_rx.await.map_err(|_| "Audio worker thread dropped the reply".to_string())??;
+
+        let mut devices = self.active_devices.lock().await;
+        devices.retain(|d| d != device_id);
+        if devices.is_empty() {
+            self.is_recording.store(false, Ordering::Relaxed);
+            println!("📴 All devices stopped, recording state set to false");
+        }
+
+        Ok(())
+    }
+
+    async fn get_chunk(&self, max_samples: Option<usize>) -> Vec<f32> {
+        let mut consumer = self.consumer.lock().await;
+        match max_samples {
+            Some(max) => consumer.pop_iter().take(max).collect(),
+            None => consumer.pop_iter().collect(),
+        }
+    }
+
+    /// Drain whatever's captured and resample it to `target_rate`/`target_channels`
+    /// (downstream ASR almost always wants 16kHz mono). The resampler keeps a
+    /// filter-state tail across calls so successive chunks of a streaming
+    /// capture don't click at the boundary.
+    async fn get_resampled(&mut self, target_rate: u32, target_channels: u16) -> Vec<f32> {
+        let samples = self.get_chunk(None).await;
+        self.resampler.process(&samples, self.sample_rate, self.channels, target_rate, target_channels)
+    }
+
+    async fn query_status(&self) -> AudioCaptureStatus {
+        AudioCaptureStatus {
+            is_recording: self.is_recording.load(Ordering::Relaxed),
+            active_devices: self.active_devices.lock().await.clone(),
+            buffer_size: self.consumer.lock().await.occupied_len(),
+        }
+    }
+
+    fn enumerate_devices(&self) -> Result<Vec<AudioDevice>, String> {
         let mut devices = Vec::new();
+        let host_id_str = format!("{:?}", self.host_id);
 
-        // Get input devices (microphones)
-        for (index, device) in self.host.input_devices()?.enumerate() {
+        for (index, device) in self.host.input_devices().map_err(|e| e.to_string())?.enumerate() {
             if let Ok(name) = device.name() {
                 if let Ok(config) = device.default_input_config() {
                     devices.push(AudioDevice {
@@ -56,18 +384,53 @@ impl AudioCapture {
                         is_system: false,
                         channels: config.channels(),
                         sample_rate: config.sample_rate().0,
+                        host_id: host_id_str.to_string(),
                     });
                 }
             }
         }
 
-        // Add system audio devices (platform-specific)
-        devices.extend(self.get_system_audio_devices());
+        devices.extend(self.get_system_audio_devices(&host_id_str));
 
         Ok(devices)
     }
 
-    fn get_system_audio_devices(&self) -> Vec<AudioDevice> {
+    /// Switch the backend used for enumeration and future captures. Streams
+    /// already open on the previous backend are stopped - the worker thread
+    /// that owns them is torn down and replaced with one built on the new
+    /// host, since a `cpal::Stream` can't be moved between hosts. Callers
+    /// that need a seamless switch should restart any active capture after
+    /// calling this.
+    async fn set_host(&mut self, host_id: cpal::HostId) -> Result<(), String> {
+        let host = cpal::host_from_id(host_id).map_err(|e| e.to_string())?;
+
+        // Dropping the old sender makes the old worker's `rx.recv()` loop exit,
+        // which drops its `HashMap<String, Stream>` and genuinely stops every
+        // stream it owned - there's no way to hand a `cpal::Stream` over to a
+        // worker built on a different host.
+        let stream_commands = spawn_stream_worker(
+            host_id,
+            self.producer.clone(),
+            self.is_recording.clone(),
+            self.active_devices.clone(),
+        );
+
+        if let Some(handle) = self.aggregate_mixer.lock().await.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.jitter_task.lock().await.take() {
+            handle.abort();
+        }
+        self.is_recording.store(false, Ordering::Relaxed);
+        self.active_devices.lock().await.clear();
+
+        self.host = host;
+        self.host_id = host_id;
+        self.stream_commands = stream_commands;
+        Ok(())
+    }
+
+    fn get_system_audio_devices(&self, host_id_str: &str) -> Vec<AudioDevice> {
         let mut devices = Vec::new();
 
         #[cfg(target_os = "windows")]
@@ -79,8 +442,9 @@ impl AudioCapture {
                 is_system: true,
                 channels: 2,
                 sample_rate: 44100,
+                host_id: host_id_str.to_string(),
             });
-            
+
             // Also check for Stereo Mix if available
             devices.push(AudioDevice {
                 id: "system_windows_stereomix".to_string(),
@@ -88,6 +452,7 @@ impl AudioCapture {
                 is_system: true,
                 channels: 2,
                 sample_rate: 44100,
+                host_id: host_id_str.to_string(),
             });
         }
 
@@ -100,8 +465,9 @@ impl AudioCapture {
                 is_system: true,
                 channels: 2,
                 sample_rate: 44100,
+                host_id: host_id_str.to_string(),
             });
-            
+
             // Fallback to BlackHole or SoundFlower if available
             devices.push(AudioDevice {
                 id: "system_macos_blackhole".to_string(),
@@ -109,6 +475,7 @@ impl AudioCapture {
                 is_system: true,
                 channels: 2,
                 sample_rate: 44100,
+                host_id: host_id_str.to_string(),
             });
         }
 
@@ -121,8 +488,9 @@ impl AudioCapture {
                 is_system: true,
                 channels: 2,
                 sample_rate: 44100,
+                host_id: host_id_str.to_string(),
             });
-            
+
             // ALSA loopback
             devices.push(AudioDevice {
                 id: "system_linux_alsa_loopback".to_string(),
@@ -130,265 +498,754 @@ impl AudioCapture {
                 is_system: true,
                 channels: 2,
                 sample_rate: 44100,
+                host_id: host_id_str.to_string(),
             });
         }
 
         devices
     }
+}
 
-    pub async fn start_capture(&mut self, device_id: String) -> Result<(), Box<dyn std::error::Error>> {
-        println!("🎵 Starting audio capture for device: {}", device_id);
-        
-        // Check if device is already capturing
-        {
-            let active_devices = self.active_devices.lock().await;
-            if active_devices.contains(&device_id) {
-                println!("⚠️ Device {} already capturing", device_id);
-                return Ok(());
+/// Runs on a dedicated tokio task for the lifetime of the app, processing
+/// one `AudioCaptureRequest` at a time so callers never contend on a shared
+/// lock the way the old `Arc<Mutex<AudioCapture>>` design did - only the
+/// actor itself ever holds `&mut AudioCaptureActorState`.
+async fn run_audio_capture_actor(mut state: AudioCaptureActorState, mut requests: mpsc::Receiver<AudioCaptureRequest>) {
+    while let Some(request) = requests.recv().await {
+        match request {
+            AudioCaptureRequest::StartCapture { device_id, reply } => {
+                let _ = reply.send(state.start_capture(device_id).await);
+            }
+            AudioCaptureRequest::StartAggregate { mic_id, system_id, reply } => {
+                let _ = reply.send(state.start_aggregate_capture(mic_id, system_id).await);
+            }
+            AudioCaptureRequest::StopDevice { device_id, reply } => {
+                let _ = reply.send(state.stop_device_capture(&device_id).await);
+            }
+            AudioCaptureRequest::StopAll { reply } => {
+                let _ = reply.send(state.stop_all().await);
+            }
+            AudioCaptureRequest::GetChunk { max_samples, reply } => {
+                let _ = reply.send(state.get_chunk(max_samples).await);
+            }
+            AudioCaptureRequest::GetResampled { target_rate, target_channels, reply } => {
+                let _ = reply.send(state.get_resampled(target_rate, target_channels).await);
+            }
+            AudioCaptureRequest::QueryStatus { reply } => {
+                let _ = reply.send(state.query_status().await);
+            }
+            AudioCaptureRequest::EnumerateDevices { reply } => {
+                let _ = reply.send(state.enumerate_devices());
+            }
+            AudioCaptureRequest::SetHost { host_id, reply } => {
+                let _ = reply.send(state.set_host(host_id).await);
+            }
+            AudioCaptureRequest::SetBufferingConfig { config } => {
+                state.buffering_config = config;
+            }
+            AudioCaptureRequest::GetBufferingStats { reply } => {
+                let _ = reply.send(*state.jitter_stats.lock().await);
             }
         }
+    }
+}
 
-        let device = if device_id.starts_with("system_") {
-            self.get_system_device(&device_id)?
-        } else {
-            // Find input device by ID
-            let device_index: usize = device_id.replace("input_", "").parse().unwrap_or(0);
-            self.host.input_devices()?
-                .nth(device_index)
-                .ok_or("Audio device not found")?
-        };
+/// Thin handle onto the capture actor spawned in `new_with_host` - cheap to
+/// clone and safe to hold directly as `Arc<AudioCapture>` Tauri state with no
+/// outer `Mutex`, since every method below is just a channel send plus an
+/// `.await` on the reply. Wrapping it in a `Mutex` would serialize unrelated
+/// commands on that lock (e.g. a slow `get_audio_data` copy blocking a
+/// concurrent `is_recording` poll) for no reason, since nothing here mutates
+/// `&self`.
+#[derive(Clone)]
+pub struct AudioCapture {
+    control_tx: mpsc::Sender<AudioCaptureRequest>,
+    is_recording: Arc<AtomicBool>,
+}
 
-        let config = if device_id.starts_with("system_") {
-            // For system audio, try different approaches based on platform
-            self.get_system_audio_config(&device, &device_id)?
-        } else {
-            device.default_input_config()?
-        };
+impl AudioCapture {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::new_with_host(cpal::default_host().id())
+    }
+
+    /// List every audio backend cpal can drive on this platform, e.g.
+    /// WASAPI/ASIO on Windows or ALSA/PulseAudio/JACK on Linux. Feed the
+    /// returned `id` into [`AudioCapture::new_with_host`] or
+    /// [`AudioCapture::set_host`] to target a specific one instead of
+    /// whatever `cpal::default_host()` happens to pick.
+    pub fn list_hosts() -> Vec<HostInfo> {
+        let default_id = cpal::default_host().id();
+        cpal::available_hosts()
+            .into_iter()
+            .map(|id| HostInfo {
+                id: format!("{:?}", id),
+                name: host_display_name(id),
+                is_default: id == default_id,
+            })
+            .collect()
+    }
 
-        let audio_data = self.audio_data.clone();
-        let is_recording = self.is_recording.clone();
-        let active_devices = self.active_devices.clone();
-        let device_id_clone = device_id.clone();
-        
-        let stream_config: cpal::StreamConfig = config.into();
-        println!("🔧 Device config: {:?}", stream_config);
+    /// Like [`AudioCapture::new`] but drives a specific backend instead of
+    /// `cpal::default_host()` - e.g. ASIO for low-latency on Windows or JACK
+    /// on Linux.
+    pub fn new_with_host(host_id: cpal::HostId) -> Result<Self, Box<dyn std::error::Error>> {
+        let state = AudioCaptureActorState::new_with_host(host_id)?;
+        let is_recording = state.is_recording.clone();
+        let (control_tx, control_rx) = mpsc::channel(32);
+        tokio::spawn(run_audio_capture_actor(state, control_rx));
 
-        // Create and start stream in a separate scope to avoid Send+Sync issues
-        {
-            let stream = device.build_input_stream(
-                &stream_config,
-                move |data: &[f32], _: &_| {
-                    // Audio data callback - store in buffer with device mixing
-                    let mut buffer = block_on(audio_data.lock());
-                    
-                    // Limit buffer size to prevent memory issues (keep last 30 seconds at 44.1kHz)
-                    let max_samples = 44100 * 30 * 2; // 30 seconds, stereo
-                    let current_len = buffer.len();
-                    if current_len > max_samples {
-                        let drain_count = current_len - max_samples;
-                        buffer.drain(0..drain_count);
+        Ok(Self { control_tx, is_recording })
+    }
+
+    async fn send_request<T>(&self, build: impl FnOnce(oneshot::Sender<T>) -> AudioCaptureRequest) -> Result<T, Box<dyn std::error::Error>> {
+        let (reply, rx) = oneshot::channel();
+        self.control_tx
+            .send(build(reply))
+            .await
+            .map_err(|_| "audio capture actor is not running")?;
+        rx.await.map_err(|_| "audio capture actor dropped its reply".into())
+    }
+
+    /// Override the jitter-buffer compensation target (default 200ms). Takes
+    /// effect the next time a compensation loop is started, i.e. on the first
+    /// `start_capture`/`start_aggregate_capture` call after `stop_capture` or
+    /// `set_host` has stopped the running loop. Fire-and-forget,
+    /// like `plugins::audio_capture::AudioChunker::set_vad_config` - no
+    /// caller has ever needed to wait for this to land before proceeding.
+    pub fn set_buffering_config(&self, config: AudioBufferingConfig) {
+        let tx = self.control_tx.clone();
+        tokio::spawn(async move {
+            let _ = tx.send(AudioCaptureRequest::SetBufferingConfig { config }).await;
+        });
+    }
+
+    /// Current jitter-buffer health: the moving-average fill level and how
+    /// many times a batch has been dropped or duplicated to correct drift.
+    pub async fn get_buffering_stats(&self) -> JitterBufferStats {
+        self.send_request(|reply| AudioCaptureRequest::GetBufferingStats { reply })
+            .await
+            .unwrap_or_default()
+    }
+
+    pub async fn set_host(&self, host_id: cpal::HostId) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_request(|reply| AudioCaptureRequest::SetHost { host_id, reply }).await?
+            .map_err(|e| e.into())
+    }
+
+    pub async fn enumerate_devices(&self) -> Result<Vec<AudioDevice>, Box<dyn std::error::Error>> {
+        self.send_request(|reply| AudioCaptureRequest::EnumerateDevices { reply }).await?
+            .map_err(|e| e.into())
+    }
+
+    pub async fn start_capture(&self, device_id: String) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_request(|reply| AudioCaptureRequest::StartCapture { device_id, reply }).await?
+            .map_err(|e| e.into())
+    }
+
+    pub async fn start_aggregate_capture(&self, mic_id: String, system_id: String) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_request(|reply| AudioCaptureRequest::StartAggregate { mic_id, system_id, reply }).await?
+            .map_err(|e| e.into())
+    }
+
+    pub async fn stop_capture(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_request(|reply| AudioCaptureRequest::StopAll { reply }).await?
+            .map_err(|e| e.into())
+    }
+
+    /// Fast, actor-free read - mirrors the real-time stream worker's error
+    /// callback, which flips this same `Arc<AtomicBool>` directly so a
+    /// caller never has to wait behind whatever the actor happens to be
+    /// doing just to check whether anything is recording.
+    pub fn is_recording(&self) -> bool {
+        self.is_recording.load(Ordering::Relaxed)
+    }
+
+    pub async fn get_audio_data(&self) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        Ok(self.send_request(|reply| AudioCaptureRequest::GetChunk { max_samples: None, reply }).await?)
+    }
+
+    pub async fn get_audio_data_chunk(&self, max_samples: usize) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        Ok(self.send_request(|reply| AudioCaptureRequest::GetChunk { max_samples: Some(max_samples), reply }).await?)
+    }
+
+    pub async fn get_audio_buffer_size(&self) -> Result<usize, Box<dyn std::error::Error>> {
+        Ok(self.send_request(|reply| AudioCaptureRequest::QueryStatus { reply }).await?.buffer_size)
+    }
+
+    pub async fn get_audio_data_resampled(&self, target_rate: u32, target_channels: u16) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        Ok(self.send_request(|reply| AudioCaptureRequest::GetResampled { target_rate, target_channels, reply }).await?)
+    }
+
+    pub async fn get_active_devices(&self) -> Vec<String> {
+        self.send_request(|reply| AudioCaptureRequest::QueryStatus { reply })
+            .await
+            .map(|status| status.active_devices)
+            .unwrap_or_default()
+    }
+
+    pub async fn stop_device_capture(&self, device_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_request(|reply| AudioCaptureRequest::StopDevice { device_id: device_id.to_string(), reply }).await?
+            .map_err(|e| e.into())
+    }
+}
+
+/// Drains the mic and system-audio staging buffers at a fixed cadence and
+/// sums whatever overlaps, sample-for-sample, into the shared output ring
+/// buffer. Accumulating first and mixing only the common prefix is itself a
+/// small jitter buffer: it tolerates one source's callback firing a little
+/// ahead of the other's without ever interleaving unaligned samples.
+async fn mix_aggregate_loop(
+    mut mic_consumer: HeapCons<f32>,
+    mut system_consumer: HeapCons<f32>,
+    main_producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+    mic_last_batch_at: Arc<AtomicU64>,
+    system_last_batch_at: Arc<AtomicU64>,
+) {
+    let mut mic_acc: Vec<f32> = Vec::new();
+    let mut sys_acc: Vec<f32> = Vec::new();
+
+    loop {
+        tokio::time::sleep(AGGREGATE_MIX_INTERVAL).await;
+
+        mic_acc.extend(mic_consumer.pop_iter());
+        sys_acc.extend(system_consumer.pop_iter());
+
+        let aligned = mic_acc.len().min(sys_acc.len());
+        if aligned == 0 {
+            continue;
+        }
+
+        let drift_ms = mic_last_batch_at.load(Ordering::Relaxed) as i64
+            - system_last_batch_at.load(Ordering::Relaxed) as i64;
+        if drift_ms.unsigned_abs() > 250 {
+            eprintln!("⚠️ Aggregate capture clock drift: {}ms between mic and system callbacks", drift_ms);
+        }
+
+        let mixed: Vec<f32> = mic_acc[..aligned]
+            .iter()
+            .zip(sys_acc[..aligned].iter())
+            .map(|(m, s)| (m + s).clamp(-1.0, 1.0))
+            .collect();
+
+        mic_acc.drain(0..aligned);
+        sys_acc.drain(0..aligned);
+
+        if let Ok(mut prod) = main_producer.try_lock() {
+            prod.push_slice(&mixed);
+        }
+    }
+}
+
+/// Watches the shared output buffer's fill level and nudges it back toward
+/// `config.target_latency_ms` - modeled on ALVR's audio buffering. Device
+/// callbacks push samples whenever their OS schedules them and callers drain
+/// the buffer whenever they poll, so over a long recording the two drift
+/// apart: the buffer either creeps toward its 30-second cap (added latency
+/// that eventually overflows) or runs dry (stutter). An EMA of the fill level
+/// smooths out per-batch jitter so corrections only fire on a genuine trend.
+async fn jitter_compensation_loop(
+    consumer: Arc<Mutex<HeapCons<f32>>>,
+    producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+    sample_rate: u32,
+    channels: u16,
+    config: AudioBufferingConfig,
+    stats: Arc<Mutex<JitterBufferStats>>,
+) {
+    let frame_rate = sample_rate as usize * channels.max(1) as usize;
+    let batch_samples = (frame_rate * config.batch_ms as usize) / 1000;
+    let target_samples = (frame_rate * config.target_latency_ms as usize) / 1000;
+    if batch_samples == 0 {
+        return;
+    }
+
+    // Smooth over roughly a second of batches so single late/early callbacks
+    // don't trigger a correction on their own.
+    let alpha = (config.batch_ms as f64 / 1000.0).clamp(0.01, 1.0);
+    let mut ema_fill = target_samples as f64;
+
+    loop {
+        tokio::time::sleep(Duration::from_millis(config.batch_ms as u64)).await;
+
+        let fill = consumer.lock().await.occupied_len();
+        ema_fill = alpha * fill as f64 + (1.0 - alpha) * ema_fill;
+
+        if ema_fill > (target_samples + batch_samples) as f64 {
+            drop_oldest_batch(&consumer, &producer, batch_samples).await;
+            stats.lock().await.drift_corrections += 1;
+        } else if ema_fill < target_samples.saturating_sub(batch_samples) as f64 {
+            duplicate_last_batch(&consumer, &producer, batch_samples).await;
+            stats.lock().await.drift_corrections += 1;
+        }
+
+        stats.lock().await.avg_fill_samples = ema_fill;
+    }
+}
+
+/// Drains the buffer, drops the oldest `batch_samples`, and cross-fades the
+/// dropped tail into the retained head before pushing the rest back - a
+/// straight truncation would leave an audible click at the splice.
+async fn drop_oldest_batch(
+    consumer: &Arc<Mutex<HeapCons<f32>>>,
+    producer: &Arc<std::sync::Mutex<HeapProd<f32>>>,
+    batch_samples: usize,
+) {
+    let buffered: Vec<f32> = consumer.lock().await.pop_iter().collect();
+    if buffered.len() <= batch_samples {
+        return;
+    }
+
+    let fade_len = JITTER_CROSSFADE_SAMPLES.min(batch_samples).min(buffered.len() - batch_samples);
+    let dropped_tail = &buffered[batch_samples - fade_len..batch_samples];
+    let mut kept = buffered[batch_samples..].to_vec();
+    crossfade_in_place(dropped_tail, &mut kept[..fade_len]);
+
+    if let Ok(mut prod) = producer.lock() {
+        prod.push_slice(&kept);
+    }
+}
+
+/// Drains the buffer, duplicates its most recent `batch_samples` to refill
+/// lost latency, and cross-fades the repeated copy against the original tail
+/// it now follows.
+async fn duplicate_last_batch(
+    consumer: &Arc<Mutex<HeapCons<f32>>>,
+    producer: &Arc<std::sync::Mutex<HeapProd<f32>>>,
+    batch_samples: usize,
+) {
+    let buffered: Vec<f32> = consumer.lock().await.pop_iter().collect();
+    if buffered.is_empty() {
+        return;
+    }
+
+    let filler_len = batch_samples.min(buffered.len());
+    let tail_start = buffered.len() - filler_len;
+    let mut filler = buffered[tail_start..].to_vec();
+
+    let fade_len = JITTER_CROSSFADE_SAMPLES.min(filler_len);
+    crossfade_in_place(&buffered[tail_start..], &mut filler[..fade_len]);
+
+    let mut rebuilt = buffered;
+    rebuilt.extend(filler);
+
+    if let Ok(mut prod) = producer.lock() {
+        prod.push_slice(&rebuilt);
+    }
+}
+
+/// Linearly cross-fades `incoming` into the start of `target`, in place,
+/// over `target.len()` samples - the splice-point click fix used by both
+/// drift-correction paths above.
+fn crossfade_in_place(incoming: &[f32], target: &mut [f32]) {
+    let len = target.len();
+    for i in 0..len.min(incoming.len()) {
+        let t = (i as f32 + 1.0) / (len as f32 + 1.0);
+        target[i] = incoming[i] * (1.0 - t) + target[i] * t;
+    }
+}
+
+/// Human-readable name for a `cpal::HostId`, since cpal itself only gives us
+/// the `Debug` form (e.g. `Wasapi`, `CoreAudio`).
+fn host_display_name(id: cpal::HostId) -> String {
+    match id {
+        #[cfg(target_os = "windows")]
+        cpal::HostId::Wasapi => "WASAPI".to_string(),
+        #[cfg(all(target_os = "windows", feature = "asio"))]
+        cpal::HostId::Asio => "ASIO".to_string(),
+        #[cfg(target_os = "macos")]
+        cpal::HostId::CoreAudio => "Core Audio".to_string(),
+        #[cfg(target_os = "linux")]
+        cpal::HostId::Alsa => "ALSA".to_string(),
+        #[cfg(all(target_os = "linux", feature = "jack"))]
+        cpal::HostId::Jack => "JACK".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Spawns the single OS thread that owns every `cpal::Stream`. It keeps its
+/// own `cpal::Host` (hosts aren't guaranteed `Send` either) and a
+/// `HashMap<String, Stream>` so `StopDevice` can drop exactly one stream -
+/// genuinely tearing down the OS handle instead of just flipping a flag.
+fn spawn_stream_worker(
+    host_id: cpal::HostId,
+    producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+    is_recording: Arc<AtomicBool>,
+    active_devices: Arc<Mutex<Vec<String>>>,
+) -> std_mpsc::Sender<StreamCommand> {
+    let (tx, rx) = std_mpsc::channel::<StreamCommand>();
+
+    std::thread::Builder::new()
+        .name("audio-stream-worker".to_string())
+        .spawn(move || {
+            // Fall back to the default host if the requested backend can't be
+            // opened on this thread (e.g. ASIO with no driver installed).
+            let host = cpal::host_from_id(host_id).unwrap_or_else(|_| cpal::default_host());
+            let mut streams: HashMap<String, cpal::Stream> = HashMap::new();
+
+            while let Ok(command) = rx.recv() {
+                match command {
+                    StreamCommand::StartCapture { device_id, reply } => {
+                        let result = start_device_stream(
+                            &host,
+                            &device_id,
+                            &mut streams,
+                            producer.clone(),
+                            Arc::new(AtomicU64::new(0)),
+                            is_recording.clone(),
+                            active_devices.clone(),
+                        );
+                        let _ = reply.send(result);
                     }
-                    
-                    // Mix audio data (simple addition for multiple sources)
-                    buffer.extend_from_slice(data);
-                },
-                move |err| {
-                    eprintln!("❌ Audio stream error for {}: {}", device_id_clone, err);
-                    // Try to remove device from active list on error
-                    let mut devices = block_on(active_devices.lock());
-                    devices.retain(|d| d != &device_id_clone);
-                    if devices.is_empty() {
-                        is_recording.store(false, Ordering::Relaxed);
+                    StreamCommand::StopDevice { device_id, reply } => {
+                        // Dropping the Stream stops and releases the OS handle.
+                        streams.remove(&device_id);
+                        let _ = reply.send(Ok(()));
                     }
-                },
-                None,
-            )?;
-
-            stream.play()?;
-            
-            // Keep stream alive by "leaking" it to avoid Send+Sync constraints
-            // This is necessary because cpal streams are not Send+Sync and cannot be stored
-            // in shared state across threads. The stream will continue running until the process ends.
-            std::mem::forget(stream);
-        } // stream variable is out of scope here
-        
-        // Add device to active list (async operation after stream is handled)
-        {
-            let mut devices = self.active_devices.lock().await;
-            devices.push(device_id.clone());
+                    StreamCommand::StopAll { reply } => {
+                        streams.clear();
+                        let _ = reply.send(Ok(()));
+                    }
+                    StreamCommand::StartAggregate {
+                        mic_id,
+                        system_id,
+                        mic_producer,
+                        system_producer,
+                        mic_last_batch_at,
+                        system_last_batch_at,
+                        reply,
+                    } => {
+                        let result = (|| {
+                            start_device_stream(&host, &mic_id, &mut streams, mic_producer, mic_last_batch_at, is_recording.clone(), active_devices.clone())?;
+                            start_device_stream(&host, &system_id, &mut streams, system_producer, system_last_batch_at, is_recording.clone(), active_devices.clone())?;
+                            Ok(())
+                        })();
+                        let _ = reply.send(result);
+                    }
+                }
+            }
+        })
+        .expect("failed to spawn audio-stream-worker thread");
+
+    tx
+}
+
+fn start_device_stream(
+    host: &cpal::Host,
+    device_id: &str,
+    streams: &mut HashMap<String, cpal::Stream>,
+    producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+    last_batch_at: Arc<AtomicU64>,
+    is_recording: Arc<AtomicBool>,
+    active_devices: Arc<Mutex<Vec<String>>>,
+) -> Result<(), String> {
+    if streams.contains_key(device_id) {
+        return Ok(());
+    }
+
+    let device = if device_id.starts_with("system_") {
+        get_system_device(host, device_id)?
+    } else {
+        let device_index: usize = device_id.replace("input_", "").parse().unwrap_or(0);
+        host.input_devices()
+            .map_err(|e| e.to_string())?
+            .nth(device_index)
+            .ok_or_else(|| "Audio device not found".to_string())?
+    };
+
+    let config = if device_id.starts_with("system_") {
+        get_system_audio_config(&device, device_id)?
+    } else {
+        device.default_input_config().map_err(|e| e.to_string())?
+    };
+
+    let stream_config: cpal::StreamConfig = config.into();
+    println!("🔧 Device config: {:?}", stream_config);
+
+    let device_id_clone = device_id.to_string();
+    let active_devices_err = active_devices;
+    let is_recording_err = is_recording;
+
+    let stream = device
+        .build_input_stream(
+            &stream_config,
+            move |data: &[f32], _: &_| {
+                // Real-time thread: never block. If another device's callback is
+                // mid-push, just drop this slice rather than wait for the lock.
+                if let Ok(mut prod) = producer.try_lock() {
+                    // Ring buffer is fixed-capacity; push_slice drops the overflow
+                    // tail instead of growing, so the callback never allocates.
+                    prod.push_slice(data);
+                }
+                // Timestamp this batch so an aggregate mixer can tell how far
+                // apart two independently clocked devices' callbacks land.
+                if let Ok(now) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+                    last_batch_at.store(now.as_millis() as u64, Ordering::Relaxed);
+                }
+            },
+            move |err| {
+                eprintln!("❌ Audio stream error for {}: {}", device_id_clone, err);
+                // Try to remove device from active list on error
+                let mut devices = block_on(active_devices_err.lock());
+                devices.retain(|d| d != &device_id_clone);
+                if devices.is_empty() {
+                    is_recording_err.store(false, Ordering::Relaxed);
+                }
+            },
+            None,
+        )
+        .map_err(|e| e.to_string())?;
+
+    stream.play().map_err(|e| e.to_string())?;
+    streams.insert(device_id.to_string(), stream);
+
+    Ok(())
+}
+
+fn get_system_audio_config(device: &cpal::Device, device_id: &str) -> Result<cpal::SupportedStreamConfig, String> {
+    // Try different config approaches based on device type
+    if device_id.contains("system_") {
+        // For system audio, prefer output config if available, fallback to input
+        if let Ok(output_config) = device.default_output_config() {
+            println!("📡 Using output config for system audio");
+            return Ok(output_config);
         }
-        
-        self.is_recording.store(true, Ordering::Relaxed);
-        
-        println!("✅ Audio capture started for device: {}", device_id);
-        Ok(())
     }
 
-    fn get_system_audio_config(&self, device: &cpal::Device, device_id: &str) -> Result<cpal::SupportedStreamConfig, Box<dyn std::error::Error>> {
-        // Try different config approaches based on device type
-        if device_id.contains("system_") {
-            // For system audio, prefer output config if available, fallback to input
-            if let Ok(output_config) = device.default_output_config() {
-                println!("📡 Using output config for system audio");
-                return Ok(output_config);
-            }
+    // Fallback to input config
+    println!("📡 Using input config for device");
+    device.default_input_config().map_err(|e| e.to_string())
+}
+
+fn get_system_device(host: &cpal::Host, device_id: &str) -> Result<cpal::Device, String> {
+    match device_id {
+        #[cfg(target_os = "windows")]
+        "system_windows_wasapi" => {
+            // Use WASAPI for Windows system audio loopback
+            println!("🪟 Attempting Windows WASAPI loopback");
+            let wasapi_host = cpal::host_from_id(cpal::available_hosts()
+                .into_iter()
+                .find(|id| *id == cpal::HostId::Wasapi)
+                .ok_or_else(|| "WASAPI not available".to_string())?)
+                .map_err(|e| e.to_string())?;
+
+            wasapi_host.default_output_device()
+                .ok_or_else(|| "No default output device found".to_string())
         }
-        
-        // Fallback to input config
-        println!("📡 Using input config for device");
-        device.default_input_config().map_err(|e| e.into())
-    }
-
-    fn get_system_device(&self, device_id: &str) -> Result<cpal::Device, Box<dyn std::error::Error>> {
-        match device_id {
-            #[cfg(target_os = "windows")]
-            "system_windows_wasapi" => {
-                // Use WASAPI for Windows system audio loopback
-                println!("🪟 Attempting Windows WASAPI loopback");
-                let host = cpal::host_from_id(cpal::available_hosts()
-                    .into_iter()
-                    .find(|id| *id == cpal::HostId::Wasapi)
-                    .ok_or("WASAPI not available")?)?;
-
-                host.default_output_device()
-                    .ok_or("No default output device found".into())
-            }
-            
-            #[cfg(target_os = "windows")]
-            "system_windows_stereomix" => {
-                // Try to find Stereo Mix device
-                println!("🪟 Looking for Stereo Mix device");
-                for device in self.host.input_devices()? {
-                    if let Ok(name) = device.name() {
-                        if name.to_lowercase().contains("stereo mix") || 
-                           name.to_lowercase().contains("what u hear") {
-                            return Ok(device);
-                        }
+
+        #[cfg(target_os = "windows")]
+        "system_windows_stereomix" => {
+            // Try to find Stereo Mix device
+            println!("🪟 Looking for Stereo Mix device");
+            for device in host.input_devices().map_err(|e| e.to_string())? {
+                if let Ok(name) = device.name() {
+                    if name.to_lowercase().contains("stereo mix") ||
+                       name.to_lowercase().contains("what u hear") {
+                        return Ok(device);
                     }
                 }
-                Err("Stereo Mix device not found".into())
             }
+            Err("Stereo Mix device not found".to_string())
+        }
 
-            #[cfg(target_os = "macos")]
-            "system_macos_screencapturekit" => {
-                // macOS ScreenCaptureKit approach - fallback to default output
-                println!("🍎 Attempting macOS ScreenCaptureKit system audio");
-                self.host.default_output_device()
-                    .ok_or("No default output device found".into())
-            }
-            
-            #[cfg(target_os = "macos")]
-            "system_macos_blackhole" => {
-                // Look for BlackHole virtual audio device
-                println!("🍎 Looking for BlackHole device");
-                for device in self.host.input_devices()? {
-                    if let Ok(name) = device.name() {
-                        if name.to_lowercase().contains("blackhole") {
-                            return Ok(device);
-                        }
+        #[cfg(target_os = "macos")]
+        "system_macos_screencapturekit" => {
+            // macOS ScreenCaptureKit approach - fallback to default output
+            println!("🍎 Attempting macOS ScreenCaptureKit system audio");
+            host.default_output_device()
+                .ok_or_else(|| "No default output device found".to_string())
+        }
+
+        #[cfg(target_os = "macos")]
+        "system_macos_blackhole" => {
+            // Look for BlackHole virtual audio device
+            println!("🍎 Looking for BlackHole device");
+            for device in host.input_devices().map_err(|e| e.to_string())? {
+                if let Ok(name) = device.name() {
+                    if name.to_lowercase().contains("blackhole") {
+                        return Ok(device);
                     }
                 }
-                Err("BlackHole device not found".into())
             }
+            Err("BlackHole device not found".to_string())
+        }
 
-            #[cfg(target_os = "linux")]
-            "system_linux_pulse_monitor" => {
-                // Linux PulseAudio monitor source
-                println!("🐧 Attempting Linux PulseAudio monitor");
-                for device in self.host.input_devices()? {
-                    if let Ok(name) = device.name() {
-                        if name.to_lowercase().contains("monitor") || 
-                           name.to_lowercase().contains("output") {
-                            return Ok(device);
-                        }
+        #[cfg(target_os = "linux")]
+        "system_linux_pulse_monitor" => {
+            // Linux PulseAudio monitor source
+            println!("🐧 Attempting Linux PulseAudio monitor");
+            for device in host.input_devices().map_err(|e| e.to_string())? {
+                if let Ok(name) = device.name() {
+                    if name.to_lowercase().contains("monitor") ||
+                       name.to_lowercase().contains("output") {
+                        return Ok(device);
                     }
                 }
-                // Fallback to default output
-                self.host.default_output_device()
-                    .ok_or("No monitor device found".into())
-            }
-            
-            #[cfg(target_os = "linux")]
-            "system_linux_alsa_loopback" => {
-                // ALSA loopback device
-                println!("🐧 Looking for ALSA loopback device");
-                self.host.default_output_device()
-                    .ok_or("No default output device found".into())
             }
+            // Fallback to default output
+            host.default_output_device()
+                .ok_or_else(|| "No monitor device found".to_string())
+        }
 
-            _ => Err("Unsupported system audio device".into())
+        #[cfg(target_os = "linux")]
+        "system_linux_alsa_loopback" => {
+            // ALSA loopback device
+            println!("🐧 Looking for ALSA loopback device");
+            host.default_output_device()
+                .ok_or_else(|| "No default output device found".to_string())
         }
+
+        _ => Err("Unsupported system audio device".to_string())
     }
+}
 
-    pub async fn stop_capture(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        println!("🛑 Stopping all audio capture streams");
-        
-        // Signal all streams to stop
-        self.is_recording.store(false, Ordering::Relaxed);
-        
-        // Clear active devices list
-        {
-            let mut devices = self.active_devices.lock().await;
-            devices.clear();
+// Half-width of the Hann-windowed sinc interpolation kernel used by
+// `StreamingResampler` - same design (and tap count) as `multi_audio.rs`'s
+// `SourceResampler` and `whisper.rs`'s `sinc_resample`.
+const RESAMPLER_HALF_TAPS: i64 = 16;
+
+/// Direct per-output-sample sinc interpolation resampler, carrying `phase`
+/// (fractional source read position) and `history` (trailing source
+/// samples) across calls so streaming chunks stay glitch-free at their
+/// boundaries instead of each being resampled in isolation. Replaces an
+/// earlier upsample-by-zero-stuffing/FIR/decimate design that fell apart at
+/// large upsample factors (e.g. 44100->16000) because a fixed 32-tap filter
+/// can't cover the stopband once the zero-stuffed signal's images are that
+/// close together - this evaluates the sinc kernel directly at each output
+/// sample's fractional source position instead, so the filter quality
+/// doesn't depend on the rate ratio.
+struct StreamingResampler {
+    step: f64,
+    phase: f64,
+    history: Vec<f32>,
+    last_source_rate: u32,
+    last_target_rate: u32,
+}
+
+impl StreamingResampler {
+    fn new() -> Self {
+        Self {
+            step: 1.0,
+            phase: 0.0,
+            history: vec![0.0; RESAMPLER_HALF_TAPS as usize],
+            last_source_rate: 0,
+            last_target_rate: 0,
         }
-        
-        // Clear audio data buffer
-        {
-            let mut buffer = self.audio_data.lock().await;
-            buffer.clear();
-        }
-        
-        // Note: We can't explicitly stop individual streams since they were "leaked"
-        // to keep them alive. They will stop automatically when the recording flag is false
-        // or when the process ends.
-        
-        println!("✅ All audio capture stopped");
-        Ok(())
     }
 
-    pub fn is_recording(&self) -> bool {
-        self.is_recording.load(Ordering::Relaxed)
+    /// Hann-windowed sinc kernel value for integer tap offset `k`, given the
+    /// fractional output position `frac` within `[0, 1)` relative to tap 0 -
+    /// i.e. `sinc(frac - k)` tapered to zero at `|frac - k| == RESAMPLER_HALF_TAPS`.
+    fn kernel(frac: f64, k: i64) -> f64 {
+        let x = frac - k as f64;
+        let half = RESAMPLER_HALF_TAPS as f64;
+        if x.abs() >= half {
+            return 0.0;
+        }
+        let sinc = if x.abs() < 1e-9 {
+            1.0
+        } else {
+            (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+        };
+        let window = 0.5 + 0.5 * (std::f64::consts::PI * x / half).cos();
+        sinc * window
     }
 
-    pub async fn get_audio_data(&self) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
-        let mut buffer = self.audio_data.lock().await;
-        Ok(buffer.drain(..).collect())
+    /// Carries the last `RESAMPLER_HALF_TAPS` samples of `input` (falling
+    /// back to whatever's left of the previous history for short chunks)
+    /// forward into `self.history` for the next `process()` call.
+    fn save_history(&mut self, input: &[f32]) {
+        let history_len = self.history.len();
+        if input.len() >= history_len {
+            self.history.copy_from_slice(&input[input.len() - history_len..]);
+        } else {
+            self.history.drain(0..input.len());
+            self.history.extend_from_slice(input);
+        }
     }
 
-    pub async fn get_audio_data_chunk(&self, max_samples: usize) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
-        let mut buffer = self.audio_data.lock().await;
-        let chunk_size = std::cmp::min(max_samples, buffer.len());
-        Ok(buffer.drain(0..chunk_size).collect())
-    }
+    fn resample(&mut self, input: &[f32]) -> Vec<f32> {
+        let half = RESAMPLER_HALF_TAPS;
+        let history = &self.history;
+        let sample_at = |idx: i64| -> f32 {
+            if idx < 0 {
+                let hist_idx = history.len() as i64 + idx;
+                if hist_idx >= 0 { history[hist_idx as usize] } else { 0.0 }
+            } else if (idx as usize) < input.len() {
+                input[idx as usize]
+            } else {
+                0.0
+            }
+        };
 
-    pub async fn get_audio_buffer_size(&self) -> Result<usize, Box<dyn std::error::Error>> {
-        let buffer = self.audio_data.lock().await;
-        Ok(buffer.len())
-    }
+        let mut out = Vec::with_capacity((input.len() as f64 / self.step) as usize + 1);
+        let mut pos = self.phase;
+        loop {
+            let i = pos.floor() as i64;
+            // The kernel needs samples up to `i + half`; once that runs past
+            // the end of this chunk, stop and let `phase` carry the
+            // remainder into the next call.
+            if i + half >= input.len() as i64 {
+                break;
+            }
+            let frac = pos - i as f64;
+            let mut acc = 0.0f64;
+            for k in -(half - 1)..=half {
+                acc += sample_at(i + k) as f64 * Self::kernel(frac, k);
+            }
+            out.push(acc as f32);
+            pos += self.step;
+        }
 
-    pub async fn get_active_devices(&self) -> Vec<String> {
-        let devices = self.active_devices.lock().await;
-        devices.clone()
+        self.phase = pos - input.len() as f64;
+        self.save_history(input);
+        out
     }
 
-    pub async fn stop_device_capture(&mut self, device_id: &str) -> Result<(), Box<dyn std::error::Error>> {
-        println!("🛑 Stopping stream for device: {}", device_id);
-        
-        // Remove device from active list
-        {
-            let mut devices = self.active_devices.lock().await;
-            devices.retain(|d| d != device_id);
-            
-            // Update recording state
-            if devices.is_empty() {
-                self.is_recording.store(false, Ordering::Relaxed);
-                println!("📴 All devices stopped, recording state set to false");
+    fn process(&mut self, input: &[f32], source_rate: u32, source_channels: u16, target_rate: u32, target_channels: u16) -> Vec<f32> {
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        // A different source/target pair invalidates the carried phase and
+        // history - they were computed for a different ratio and would just
+        // introduce noise.
+        if source_rate != self.last_source_rate || target_rate != self.last_target_rate {
+            self.step = source_rate as f64 / target_rate.max(1) as f64;
+            self.phase = 0.0;
+            self.history = vec![0.0; RESAMPLER_HALF_TAPS as usize];
+            self.last_source_rate = source_rate;
+            self.last_target_rate = target_rate;
+        }
+
+        let mono = downmix_to_mono(input, source_channels);
+
+        let resampled = if source_rate == target_rate {
+            mono
+        } else {
+            self.resample(&mono)
+        };
+
+        match target_channels {
+            1 => resampled,
+            n => {
+                let mut out = Vec::with_capacity(resampled.len() * n as usize);
+                for s in resampled {
+                    for _ in 0..n {
+                        out.push(s);
+                    }
+                }
+                out
             }
         }
-        
-        // Note: We can't explicitly stop individual streams since they were "leaked"
-        // The stream will continue until the recording flag check stops it or process ends
-        
-        Ok(())
     }
 }
+
+fn downmix_to_mono(samples: &[f32], channels: u16) -> Vec<f32> {
+    let channels = channels.max(1) as usize;
+    if channels == 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
@@ -0,0 +1,331 @@
+//! Chunk audio encoders beyond plain WAV: Ogg-Opus (lossy, ~5-10x smaller,
+//! used for on-prem upload of meeting audio) and FLAC (lossless). Selected
+//! per-session via `AudioChunker::set_chunk_codec` / `ac_set_chunk_format`.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Codec `AudioChunker` writes each `chunk_NNNN.*` file in. `Wav` (the
+/// default) keeps full PCM fidelity; `Opus`/`Flac` trade it for size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChunkCodec {
+    Wav,
+    Opus,
+    Flac,
+}
+
+impl ChunkCodec {
+    /// File extension (and `ChunkEvent.codec` value) for this codec.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ChunkCodec::Wav => "wav",
+            ChunkCodec::Opus => "opus",
+            ChunkCodec::Flac => "flac",
+        }
+    }
+}
+
+impl Default for ChunkCodec {
+    fn default() -> Self {
+        ChunkCodec::Wav
+    }
+}
+
+/// Default Opus bitrate for speech chunks - intelligible meeting audio at a
+/// fraction of WAV's size.
+pub const DEFAULT_OPUS_BITRATE: i32 = 24_000;
+
+/// Downmixes interleaved `data` to mono and resamples it from `source_rate`
+/// to [`OPUS_TARGET_RATE`] via a Hann-windowed sinc kernel, the same
+/// band-limited approach `audio.rs`'s `StreamingResampler` and
+/// `multi_audio.rs`'s `SourceResampler` use - just one-shot rather than
+/// streaming, since each chunk file is already a self-contained unit with
+/// no filter state to carry across calls.
+fn resample_mono_16k(data: &[f32], source_rate: u32, channels: u16) -> Vec<f32> {
+    const HALF_TAPS: i64 = 16;
+
+    let channels = channels.max(1) as usize;
+    let mono: Vec<f32> = if channels == 1 {
+        data.to_vec()
+    } else {
+        data.chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect()
+    };
+
+    if mono.is_empty() || source_rate == OPUS_TARGET_RATE {
+        return mono;
+    }
+
+    let ratio = OPUS_TARGET_RATE as f64 / source_rate as f64;
+    let out_len = ((mono.len() as f64) * ratio).round() as usize;
+    let half = HALF_TAPS as f64;
+
+    let mut out = Vec::with_capacity(out_len);
+    for i in 0..out_len {
+        let src_pos = i as f64 / ratio;
+        let center = src_pos.floor() as i64;
+        let frac = src_pos - center as f64;
+
+        let mut acc = 0.0f64;
+        let mut weight_sum = 0.0f64;
+        for k in -HALF_TAPS..=HALF_TAPS {
+            let idx = center + k;
+            if idx < 0 || idx as usize >= mono.len() {
+                continue;
+            }
+            let x = frac - k as f64;
+            let sinc = if x.abs() < 1e-9 {
+                1.0
+            } else {
+                (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+            };
+            let window = 0.5 + 0.5 * (std::f64::consts::PI * x / half).cos();
+            let w = sinc * window;
+            acc += mono[idx as usize] as f64 * w;
+            weight_sum += w;
+        }
+        // Normalizing by the weight actually applied (rather than assuming
+        // unity gain) keeps the edges of the clip from fading out, since
+        // near a boundary some taps fall outside `mono` and are skipped.
+        out.push(if weight_sum.abs() > 1e-9 { (acc / weight_sum) as f32 } else { 0.0 });
+    }
+    out
+}
+
+/// Opus's native sample rates don't include most capture devices' actual
+/// rates (44.1/48kHz aside), so every chunk is downmixed and resampled to
+/// this rate before encoding - conveniently also Whisper's native rate, so
+/// the compressed chunk needs no further resampling downstream.
+const OPUS_TARGET_RATE: u32 = 16_000;
+
+/// Encodes `data` (interleaved f32 PCM at `channels` channels, `sample_rate`
+/// Hz) into an Ogg-Opus stream at `path`, framed into 20ms blocks per the
+/// request's framing. Downmixes to mono and resamples to
+/// [`OPUS_TARGET_RATE`] first, since Opus only accepts a handful of fixed
+/// rates.
+pub fn write_opus_chunk(path: &Path, sample_rate: u32, channels: u16, data: &[f32], bitrate: i32) -> Result<()> {
+    use audiopus::{coder::Encoder, Application, Channels, SampleRate};
+    use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+
+    let data = resample_mono_16k(data, sample_rate, channels);
+    let sample_rate = OPUS_TARGET_RATE;
+    let opus_rate = SampleRate::Hz16000;
+
+    let mut encoder = Encoder::new(opus_rate, Channels::Mono, Application::Voip)
+        .map_err(|e| anyhow!("failed to create Opus encoder: {:?}", e))?;
+    encoder
+        .set_bitrate(audiopus::Bitrate::BitsPerSecond(bitrate))
+        .map_err(|e| anyhow!("failed to set Opus bitrate: {:?}", e))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::fs::File::create(path)?;
+    let mut packet_writer = PacketWriter::new(file);
+    let serial = 1u32;
+
+    packet_writer.write_packet(opus_head_packet(sample_rate), serial, PacketWriteEndInfo::EndPage, 0)?;
+    packet_writer.write_packet(opus_tags_packet(), serial, PacketWriteEndInfo::EndPage, 0)?;
+
+    let frame_samples = (sample_rate as usize * 20) / 1000;
+    let mut encoded = vec![0u8; 4000];
+    let mut granule: u64 = 0;
+    let mut offset = 0;
+
+    while offset < data.len() {
+        let end = (offset + frame_samples).min(data.len());
+        let mut frame = data[offset..end].to_vec();
+        frame.resize(frame_samples, 0.0);
+
+        let len = encoder
+            .encode_float(&frame, &mut encoded)
+            .map_err(|e| anyhow!("Opus encode failed: {:?}", e))?;
+        granule += frame_samples as u64;
+        offset = end;
+
+        let end_info = if offset >= data.len() {
+            PacketWriteEndInfo::EndStream
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+        packet_writer.write_packet(encoded[..len].to_vec(), serial, end_info, granule)?;
+    }
+
+    Ok(())
+}
+
+/// Minimal but spec-conformant `OpusHead` identification packet (mono,
+/// no pre-skip, channel mapping family 0).
+fn opus_head_packet(sample_rate: u32) -> Vec<u8> {
+    let mut head = Vec::with_capacity(19);
+    head.extend_from_slice(b"OpusHead");
+    head.push(1); // version
+    head.push(1); // channel count
+    head.extend_from_slice(&0u16.to_le_bytes()); // pre-skip
+    head.extend_from_slice(&sample_rate.to_le_bytes()); // input sample rate
+    head.extend_from_slice(&0i16.to_le_bytes()); // output gain
+    head.push(0); // channel mapping family
+    head
+}
+
+/// Minimal `OpusTags` comment packet with no user comments.
+fn opus_tags_packet() -> Vec<u8> {
+    let vendor = b"on-prem-ai-note-taker";
+    let mut tags = Vec::with_capacity(8 + 4 + vendor.len() + 4);
+    tags.extend_from_slice(b"OpusTags");
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor);
+    tags.extend_from_slice(&0u32.to_le_bytes());
+    tags
+}
+
+/// Decodes any `ChunkCodec`'s chunk file back into mono i16 PCM, for the
+/// coordinator's WAV-based post-processing (final.wav assembly, range
+/// extraction) to consume regardless of which codec recorded it.
+pub fn decode_chunk_samples(path: &Path) -> Result<(Vec<i16>, u32)> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("wav") => decode_wav_chunk(path),
+        Some("flac") => decode_flac_chunk(path),
+        Some("opus") => decode_opus_chunk(path),
+        other => Err(anyhow!("unsupported chunk extension: {:?}", other)),
+    }
+}
+
+/// Reads back a `write_wav_chunk`-produced file regardless of which
+/// `SampleFormat`/channel count it was written with - matches on
+/// `spec.sample_format`/bit depth the same way `fs.rs::load_recording_wav`
+/// does, then downmixes to mono, since every `decode_chunk_samples` caller
+/// expects mono i16 PCM.
+fn decode_wav_chunk(path: &Path) -> Result<(Vec<i16>, u32)> {
+    let mut reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+
+    let normalized: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<std::result::Result<Vec<f32>, _>>()?,
+        hound::SampleFormat::Int => {
+            let max = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / max))
+                .collect::<std::result::Result<Vec<f32>, _>>()?
+        }
+    };
+
+    let mono = downmix_to_mono(normalized, spec.channels);
+    let samples: Vec<i16> = mono
+        .iter()
+        .map(|s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+        .collect();
+    Ok((samples, spec.sample_rate))
+}
+
+fn downmix_to_mono(samples: Vec<f32>, channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return samples;
+    }
+    let channels = channels as usize;
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
+fn decode_flac_chunk(path: &Path) -> Result<(Vec<i16>, u32)> {
+    let mut reader = claxon::FlacReader::open(path).map_err(|e| anyhow!("failed to open FLAC chunk: {}", e))?;
+    let sample_rate = reader.streaminfo().sample_rate;
+    let samples: Vec<i16> = reader
+        .samples()
+        .map(|s| s.map(|v| v as i16))
+        .collect::<std::result::Result<Vec<i16>, _>>()
+        .map_err(|e| anyhow!("failed to decode FLAC chunk: {}", e))?;
+    Ok((samples, sample_rate))
+}
+
+fn decode_opus_chunk(path: &Path) -> Result<(Vec<i16>, u32)> {
+    use audiopus::{coder::Decoder, Channels, SampleRate};
+    use ogg::reading::PacketReader;
+
+    let file = std::fs::File::open(path)?;
+    let mut packet_reader = PacketReader::new(file);
+
+    let mut sample_rate = 0u32;
+    let mut decoder: Option<Decoder> = None;
+    let mut out_samples: Vec<i16> = Vec::new();
+    // Largest Opus frame (120ms at 48kHz, mono).
+    let mut pcm_buf = vec![0f32; 5760];
+
+    while let Some(packet) = packet_reader.read_packet()? {
+        let data = &packet.data;
+        if data.starts_with(b"OpusHead") {
+            sample_rate = u32::from_le_bytes([data[12], data[13], data[14], data[15]]);
+            let opus_rate = match sample_rate {
+                48000 => SampleRate::Hz48000,
+                24000 => SampleRate::Hz24000,
+                16000 => SampleRate::Hz16000,
+                12000 => SampleRate::Hz12000,
+                8000 => SampleRate::Hz8000,
+                other => return Err(anyhow!("unsupported Opus sample rate {}", other)),
+            };
+            decoder = Some(
+                Decoder::new(opus_rate, Channels::Mono)
+                    .map_err(|e| anyhow!("failed to create Opus decoder: {:?}", e))?,
+            );
+            continue;
+        }
+        if data.starts_with(b"OpusTags") {
+            continue;
+        }
+
+        let decoder = decoder
+            .as_mut()
+            .ok_or_else(|| anyhow!("Opus audio packet arrived before OpusHead"))?;
+        let samples = decoder
+            .decode_float(Some(data.as_slice()), &mut pcm_buf, false)
+            .map_err(|e| anyhow!("Opus decode failed: {:?}", e))?;
+        out_samples.extend(
+            pcm_buf[..samples]
+                .iter()
+                .map(|s| (s.max(-1.0).min(1.0) * i16::MAX as f32) as i16),
+        );
+    }
+
+    if sample_rate == 0 {
+        return Err(anyhow!("no OpusHead packet found in {}", path.display()));
+    }
+
+    Ok((out_samples, sample_rate))
+}
+
+/// Losslessly encodes `data` (mono f32 PCM at `sample_rate`) to FLAC at
+/// `path`, quantizing to 16-bit int the same way `write_wav_chunk` does.
+pub fn write_flac_chunk(path: &Path, sample_rate: u32, data: &[f32]) -> Result<()> {
+    use flac_bound::FlacEncoder;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut encoder = FlacEncoder::new()
+        .ok_or_else(|| anyhow!("failed to allocate FLAC encoder"))?
+        .channels(1)
+        .bits_per_sample(16)
+        .sample_rate(sample_rate)
+        .compression_level(5)
+        .init_file(path)
+        .map_err(|e| anyhow!("failed to initialize FLAC encoder: {:?}", e))?;
+
+    let samples: Vec<i32> = data
+        .iter()
+        .map(|s| (s.max(-1.0).min(1.0) * i16::MAX as f32) as i32)
+        .collect();
+    encoder
+        .process_interleaved(&samples, samples.len() as u32)
+        .map_err(|e| anyhow!("FLAC encode failed: {:?}", e))?;
+    encoder
+        .finish()
+        .map_err(|e| anyhow!("failed to finalize FLAC stream: {:?}", e.0))?;
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! Persistent app settings and credential storage. Previously everything
+//! here was read from env vars with hardcoded fallbacks baked into the
+//! binary (see `main.rs`'s old auth-injection setup block) and nothing
+//! about a user's chosen devices, Whisper quality, or language survived a
+//! restart. `AppConfig` is a small JSON file under the platform config dir
+//! (`AppHandle::path().app_config_dir()`), loaded once via `load_config` and
+//! written back via `save_config`/`update_config`; the basic-auth password
+//! lives in the OS keychain via the `keyring` crate instead of plaintext.
+
+use crate::whisper::WhisperQuality;
+use keyring::Entry;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+const CONFIG_FILE_NAME: &str = "settings.json";
+
+/// Service name the basic-auth credential is filed under in the OS
+/// keychain - scopes it apart from any other app using the same keyring.
+const KEYRING_SERVICE: &str = "on-prem-ai-note-taker";
+
+/// Everything about a user's setup that used to reset on every launch:
+/// which devices they picked, how they want Whisper to transcribe, and how
+/// aggressively to denoise. Applied on startup by `initialize_whisper` and
+/// read by the frontend to restore its device selection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    pub selected_mic_id: Option<String>,
+    pub selected_system_id: Option<String>,
+    pub whisper_quality: WhisperQuality,
+    pub preferred_language: Option<String>,
+    pub noise_reduction_strength: f32,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            selected_mic_id: None,
+            selected_system_id: None,
+            whisper_quality: WhisperQuality::Maximum,
+            preferred_language: None,
+            noise_reduction_strength: 0.5,
+        }
+    }
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = app.path().app_config_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir.join(CONFIG_FILE_NAME))
+}
+
+/// Loads `AppConfig` from disk, falling back to defaults if the file is
+/// missing, unreadable, or fails to parse - a corrupt settings file should
+/// never stop the app from starting.
+pub fn load_config(app: &AppHandle) -> AppConfig {
+    config_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_config(app: &AppHandle, config: &AppConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let path = config_path(app)?;
+    let contents = serde_json::to_string_pretty(config)?;
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Stores `password` in the OS keychain under `username`, replacing the
+/// shipped default password that used to be baked into the binary.
+pub fn store_credentials(username: &str, password: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let entry = Entry::new(KEYRING_SERVICE, username)?;
+    entry.set_password(password)?;
+    Ok(())
+}
+
+/// Looks up `username`'s basic-auth password in the OS keychain. Returns
+/// `None` if nothing has been stored yet (no account set up, or
+/// `clear_credentials` was called) rather than erroring, since "no
+/// credentials configured" is an expected, not exceptional, state.
+pub fn get_credentials(username: &str) -> Option<String> {
+    Entry::new(KEYRING_SERVICE, username).ok()?.get_password().ok()
+}
+
+fn clear_credentials_impl(username: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let entry = Entry::new(KEYRING_SERVICE, username)?;
+    entry.delete_credential()?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_config(app: AppHandle) -> Result<AppConfig, String> {
+    Ok(load_config(&app))
+}
+
+#[tauri::command]
+pub async fn update_config(app: AppHandle, config: AppConfig) -> Result<(), String> {
+    save_config(&app, &config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_credentials(username: String, password: String) -> Result<(), String> {
+    store_credentials(&username, &password).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_credentials(username: String) -> Result<(), String> {
+    clear_credentials_impl(&username).map_err(|e| e.to_string())
+}
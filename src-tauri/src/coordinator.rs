@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::{fs::OpenOptions, io::Write, path::PathBuf};
+use std::{fs::OpenOptions, io::Write, path::PathBuf, sync::Arc};
 use tauri::{AppHandle, Emitter};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,17 +35,7 @@ impl Coordinator {
 
         // Diarize (best-effort; may fail if service not running)
         let speaker = match crate::whisper::diarize_wav_file(chunk_path.to_string()).await {
-            Ok(turns) => {
-                let mid = (start + end) / 2.0;
-                let mut sp: Option<String> = None;
-                for t in turns {
-                    if mid >= t.start && mid <= t.end {
-                        sp = Some(t.speaker);
-                        break;
-                    }
-                }
-                sp
-            },
+            Ok(turns) => speaker_for_midpoint(&turns, (start + end) / 2.0),
             Err(_) => None,
         };
 
@@ -78,6 +68,98 @@ impl Coordinator {
         }
     }
 
+    /// Re-runs ASR + diarization over `[start_sec, end_sec]` of a finished
+    /// session and splices the result back into `transcript.jsonl` (and
+    /// `final.jsonl`/`.txt`/`.srt` if `post_process` already ran), replacing
+    /// any segments whose midpoint falls inside the range. Fire-and-forget
+    /// counterpart to `handle_chunk`: emits `transcript:partial` for the new
+    /// segment and `transcript:reprocessed` once the files are updated.
+    pub async fn retranscribe_range(&self, session_dir: &str, start_sec: f32, end_sec: f32) {
+        match self.reprocess_range(session_dir, start_sec, end_sec).await {
+            Ok(segments) => {
+                for seg in &segments {
+                    let partial = TranscriptPartial {
+                        session_id: session_dir.to_string(),
+                        chunk_path: seg.chunk.clone(),
+                        start: seg.start,
+                        end: seg.end,
+                        text: seg.text.clone(),
+                        speaker: seg.speaker.clone(),
+                    };
+                    let _ = self.app.emit("transcript:partial", &partial);
+                }
+                let _ = self.app.emit(
+                    "transcript:reprocessed",
+                    serde_json::json!({ "session_dir": session_dir, "start_sec": start_sec, "end_sec": end_sec }),
+                );
+            }
+            Err(e) => eprintln!("Retranscribe range error: {}", e),
+        }
+    }
+
+    /// Blocking counterpart to `retranscribe_range`: returns the new segments
+    /// directly instead of only emitting `transcript:partial` for them,
+    /// mirroring the fetch / fetch-blocking pairing used elsewhere.
+    pub async fn retranscribe_range_blocking(
+        &self,
+        session_dir: &str,
+        start_sec: f32,
+        end_sec: f32,
+    ) -> Result<Vec<TranscriptSegment>, String> {
+        let segments = self
+            .reprocess_range(session_dir, start_sec, end_sec)
+            .await
+            .map_err(|e| e.to_string())?;
+        let _ = self.app.emit(
+            "transcript:reprocessed",
+            serde_json::json!({ "session_dir": session_dir, "start_sec": start_sec, "end_sec": end_sec }),
+        );
+        Ok(segments)
+    }
+
+    async fn reprocess_range(
+        &self,
+        session_dir: &str,
+        start_sec: f32,
+        end_sec: f32,
+    ) -> anyhow::Result<Vec<TranscriptSegment>> {
+        let dir = PathBuf::from(session_dir);
+        // Named after the range (not a fixed/temp name) and kept on disk
+        // rather than deleted after transcription - `new_segment.chunk`
+        // below points at this file, and that reference gets spliced into
+        // `transcript.jsonl`/`final.jsonl` for good, so the file has to
+        // keep existing for as long as the transcript does.
+        let slice_path = dir.join(format!("retranscribe_{:.3}_{:.3}.wav", start_sec, end_sec));
+        extract_range_wav(&dir, start_sec, end_sec, &slice_path)?;
+        let slice_path_str = slice_path.to_string_lossy().to_string();
+
+        let text = crate::whisper::LocalWhisperService::new(crate::whisper::WhisperConfig::default())
+            .transcribe_wav_file(&slice_path_str)
+            .await
+            .unwrap_or_default();
+        let turns = crate::whisper::diarize_wav_file(slice_path_str.clone())
+            .await
+            .unwrap_or_default();
+
+        let new_segment = TranscriptSegment {
+            start: start_sec,
+            end: end_sec,
+            text,
+            speaker: speaker_for_midpoint(&turns, (start_sec + end_sec) / 2.0),
+            chunk: slice_path_str,
+        };
+
+        splice_segment(&dir.join("transcript.jsonl"), start_sec, end_sec, &new_segment)?;
+        if dir.join("final.jsonl").exists() {
+            splice_segment(&dir.join("final.jsonl"), start_sec, end_sec, &new_segment)?;
+            let segments = read_transcript_jsonl(&dir.join("final.jsonl"))?;
+            write_final_txt(&dir.join("final.txt"), &segments)?;
+            write_final_srt(&dir.join("final.srt"), &segments)?;
+        }
+
+        Ok(vec![new_segment])
+    }
+
     pub async fn post_process(&self, session_dir: &str) {
         let dir = PathBuf::from(session_dir);
         let final_wav = dir.join("final.wav");
@@ -86,11 +168,108 @@ impl Coordinator {
             return;
         }
 
-        // Global diarization
-        let _ = crate::whisper::diarize_wav_file(final_wav.to_string_lossy().to_string()).await;
-        // TODO: relabel all segments in transcript.jsonl using global turns
-        // TODO: generate final.jsonl, final.txt, final.srt
+        // Global diarization over the whole recording, used to relabel
+        // every chunk-level segment with a speaker consistent across chunks
+        // (per-chunk diarization in handle_chunk can't see other chunks).
+        let turns = match crate::whisper::diarize_wav_file(final_wav.to_string_lossy().to_string()).await {
+            Ok(turns) => turns,
+            Err(e) => {
+                eprintln!("Global diarization error: {}", e);
+                return;
+            }
+        };
+
+        let segments = match read_transcript_jsonl(&dir.join("transcript.jsonl")) {
+            Ok(segments) => segments,
+            Err(e) => {
+                eprintln!("Read transcript error: {}", e);
+                return;
+            }
+        };
+
+        let relabeled: Vec<TranscriptSegment> = segments
+            .into_iter()
+            .map(|mut seg| {
+                seg.speaker = speaker_for_midpoint(&turns, (seg.start + seg.end) / 2.0);
+                seg
+            })
+            .collect();
+
+        if let Err(e) = write_segments_jsonl(&dir.join("final.jsonl"), &relabeled) {
+            eprintln!("Write final.jsonl error: {}", e);
+        }
+        if let Err(e) = write_final_txt(&dir.join("final.txt"), &relabeled) {
+            eprintln!("Write final.txt error: {}", e);
+        }
+        if let Err(e) = write_final_srt(&dir.join("final.srt"), &relabeled) {
+            eprintln!("Write final.srt error: {}", e);
+        }
+    }
+}
+
+/// Finds the speaker whose global turn contains the given midpoint, matching
+/// the per-chunk matching logic in `handle_chunk`.
+fn speaker_for_midpoint(turns: &[crate::whisper::SpeakerTurn], mid: f32) -> Option<String> {
+    turns
+        .iter()
+        .find(|t| mid >= t.start && mid <= t.end)
+        .map(|t| t.speaker.clone())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TranscriptSegment {
+    pub(crate) start: f32,
+    pub(crate) end: f32,
+    pub(crate) text: String,
+    pub(crate) speaker: Option<String>,
+    pub(crate) chunk: String,
+}
+
+fn read_transcript_jsonl(path: &PathBuf) -> anyhow::Result<Vec<TranscriptSegment>> {
+    let content = std::fs::read_to_string(path)?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+fn write_segments_jsonl(path: &PathBuf, segments: &[TranscriptSegment]) -> std::io::Result<()> {
+    let mut out = String::new();
+    for seg in segments {
+        out.push_str(&serde_json::to_string(seg).unwrap_or_default());
+        out.push('\n');
+    }
+    std::fs::write(path, out)
+}
+
+fn write_final_txt(path: &PathBuf, segments: &[TranscriptSegment]) -> std::io::Result<()> {
+    let mut out = String::new();
+    for seg in segments {
+        out.push_str(&format!(
+            "[{} - {}] {}: {}\n",
+            hhmmss(seg.start as f64),
+            hhmmss(seg.end as f64),
+            seg.speaker.clone().unwrap_or("Speaker".into()),
+            seg.text,
+        ));
+    }
+    std::fs::write(path, out)
+}
+
+fn write_final_srt(path: &PathBuf, segments: &[TranscriptSegment]) -> std::io::Result<()> {
+    let mut out = String::new();
+    for (i, seg) in segments.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}: {}\n\n",
+            i + 1,
+            srt_timestamp(seg.start as f64),
+            srt_timestamp(seg.end as f64),
+            seg.speaker.clone().unwrap_or("Speaker".into()),
+            seg.text,
+        ));
     }
+    std::fs::write(path, out)
 }
 
 fn append_file(path: &PathBuf, data: &[u8]) -> std::io::Result<()> {
@@ -106,30 +285,162 @@ fn hhmmss(seconds: f64) -> String {
     format!("{:02}:{:02}:{:02}", h, m, sec)
 }
 
+/// Millisecond-accurate `HH:MM:SS,mmm` timestamp, as required by the SRT format.
+fn srt_timestamp(seconds: f64) -> String {
+    let total_ms = (seconds * 1000.0).round() as u64;
+    let ms = total_ms % 1000;
+    let total_s = total_ms / 1000;
+    let h = total_s / 3600;
+    let m = (total_s % 3600) / 60;
+    let s = total_s % 60;
+    format!("{:02}:{:02}:{:02},{:03}", h, m, s, ms)
+}
+
+/// Chunk extensions `AudioChunker` may have written, in whatever codec was
+/// active for the session (see `chunk_codec::ChunkCodec`).
+const CHUNK_EXTENSIONS: [&str; 3] = ["wav", "opus", "flac"];
+
 fn concat_wavs_in_dir(session_dir: &PathBuf, out: &PathBuf) -> anyhow::Result<()> {
     use anyhow::anyhow;
     let mut entries: Vec<_> = std::fs::read_dir(session_dir)?
         .filter_map(|e| e.ok())
         .map(|e| e.path())
-        .filter(|p| p.extension().map(|e| e == "wav").unwrap_or(false) && p.file_name().unwrap_or_default().to_string_lossy().starts_with("chunk_"))
+        .filter(|p| {
+            p.extension()
+                .and_then(|e| e.to_str())
+                .map(|e| CHUNK_EXTENSIONS.contains(&e))
+                .unwrap_or(false)
+                && p.file_name().unwrap_or_default().to_string_lossy().starts_with("chunk_")
+        })
         .collect();
     entries.sort();
     if entries.is_empty() { return Err(anyhow!("no chunks")); }
 
-    // Read all, assume same spec as chunks (mono 16-bit, sample rate either 48k or 16k depending on capture)
-    let mut spec: Option<hound::WavSpec> = None;
+    // Decode every chunk to mono i16 regardless of its codec; assume same
+    // sample rate across chunks (mono 16-bit, sample rate either 48k or 16k
+    // depending on capture).
+    let mut sample_rate: Option<u32> = None;
     let mut all_samples: Vec<i16> = Vec::new();
     for p in entries {
-        let mut reader = hound::WavReader::open(&p)?;
-        let rspec = reader.spec();
-        if spec.is_none() { spec = Some(rspec); }
-        for s in reader.samples::<i16>() { all_samples.push(s?); }
+        let (samples, rate) = crate::chunk_codec::decode_chunk_samples(&p)?;
+        if sample_rate.is_none() { sample_rate = Some(rate); }
+        all_samples.extend(samples);
     }
-    let spec = spec.unwrap();
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: sample_rate.unwrap(),
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
     let mut writer = hound::WavWriter::create(out, spec)?;
     for s in all_samples { writer.write_sample(s)?; }
     writer.finalize()?;
     Ok(())
 }
 
+/// Walks the main `chunk_NNNN.wav` files (skipping the `_mic`/`_sys`
+/// side-tracks) in arrival order, tracking elapsed seconds per chunk, and
+/// writes out exactly the samples overlapping `[start_sec, end_sec]` -
+/// trimming the head of the first overlapping chunk and the tail of the
+/// last one.
+fn extract_range_wav(session_dir: &PathBuf, start_sec: f32, end_sec: f32, out: &PathBuf) -> anyhow::Result<()> {
+    use anyhow::anyhow;
+    let mut entries: Vec<_> = std::fs::read_dir(session_dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.extension()
+                .and_then(|e| e.to_str())
+                .map(|e| CHUNK_EXTENSIONS.contains(&e))
+                .unwrap_or(false)
+                && p.file_stem().map(|s| {
+                    let s = s.to_string_lossy();
+                    s.starts_with("chunk_") && s.len() == "chunk_0000".len()
+                }).unwrap_or(false)
+        })
+        .collect();
+    entries.sort();
+    if entries.is_empty() { return Err(anyhow!("no chunks found in {}", session_dir.display())); }
+
+    let mut sample_rate: Option<u32> = None;
+    let mut out_samples: Vec<i16> = Vec::new();
+    let mut elapsed_sec: f32 = 0.0;
+
+    for p in entries {
+        let (samples, rate) = crate::chunk_codec::decode_chunk_samples(&p)?;
+        if sample_rate.is_none() { sample_rate = Some(rate); }
+        let frame_rate = (rate as f32).max(1.0);
+        let chunk_dur = samples.len() as f32 / frame_rate;
+        let chunk_start = elapsed_sec;
+        let chunk_end = elapsed_sec + chunk_dur;
+
+        if chunk_end > start_sec && chunk_start < end_sec {
+            let trim_start = (((start_sec - chunk_start).max(0.0)) * frame_rate).round() as usize;
+            let trim_end = (((end_sec - chunk_start).min(chunk_dur)) * frame_rate).round() as usize;
+            if trim_end > trim_start {
+                out_samples.extend_from_slice(&samples[trim_start..trim_end.min(samples.len())]);
+            }
+        }
+
+        elapsed_sec = chunk_end;
+    }
+
+    let sample_rate = sample_rate.ok_or_else(|| anyhow!("no chunk spec found in {}", session_dir.display()))?;
+    if out_samples.is_empty() {
+        return Err(anyhow!("requested range [{}, {}] has no overlapping audio", start_sec, end_sec));
+    }
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(out, spec)?;
+    for s in out_samples { writer.write_sample(s)?; }
+    writer.finalize()?;
+    Ok(())
+}
+
+/// Replaces any segments in `path` whose midpoint falls inside
+/// `[start_sec, end_sec]` with `new_segment`, re-sorting by start time.
+fn splice_segment(path: &PathBuf, start_sec: f32, end_sec: f32, new_segment: &TranscriptSegment) -> anyhow::Result<()> {
+    let mut segments = if path.exists() { read_transcript_jsonl(path)? } else { Vec::new() };
+    segments.retain(|seg| {
+        let mid = (seg.start + seg.end) / 2.0;
+        mid < start_sec || mid > end_sec
+    });
+    segments.push(new_segment.clone());
+    segments.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap_or(std::cmp::Ordering::Equal));
+    write_segments_jsonl(path, &segments)?;
+    Ok(())
+}
+
+/// Fire-and-forget: kicks off `Coordinator::retranscribe_range` in the
+/// background and returns immediately, letting the frontend watch for
+/// `transcript:partial`/`transcript:reprocessed` instead of blocking.
+#[tauri::command]
+pub async fn retranscribe_range(
+    coord_state: tauri::State<'_, Arc<tokio::sync::Mutex<Coordinator>>>,
+    session_dir: String,
+    start_sec: f32,
+    end_sec: f32,
+) -> Result<(), String> {
+    let coord_state = coord_state.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let coordinator = coord_state.lock().await;
+        coordinator.retranscribe_range(&session_dir, start_sec, end_sec).await;
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn retranscribe_range_blocking(
+    coord_state: tauri::State<'_, Arc<tokio::sync::Mutex<Coordinator>>>,
+    session_dir: String,
+    start_sec: f32,
+    end_sec: f32,
+) -> Result<Vec<TranscriptSegment>, String> {
+    let coordinator = coord_state.lock().await;
+    coordinator.retranscribe_range_blocking(&session_dir, start_sec, end_sec).await
+}
 
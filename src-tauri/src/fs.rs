@@ -1,6 +1,7 @@
 use std::fs;
 use std::path::Path;
 use serde::{Deserialize, Serialize};
+use crate::chunk_codec::{self, ChunkCodec};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileInfo {
@@ -41,6 +42,115 @@ impl FileSystemManager {
         Ok(filepath.to_string_lossy().to_string())
     }
 
+    /// Write captured samples out as a proper RIFF/WAVE file (fmt + data
+    /// chunks via `hound`, same as the WAV chunks `AudioChunker` already
+    /// writes) instead of a raw byte blob, so recordings are playable in any
+    /// audio tool and re-loadable for transcription.
+    pub async fn save_recording_wav(
+        &self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        channels: u16,
+        filename: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let app_data_dir = self.ensure_app_data_dir().await?;
+        let filepath = Path::new(&app_data_dir).join("recordings").join(filename);
+
+        if let Some(parent) = filepath.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let spec = hound::WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&filepath, spec)?;
+        for sample in samples {
+            let pcm = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            writer.write_sample(pcm)?;
+        }
+        writer.finalize()?;
+
+        Ok(filepath.to_string_lossy().to_string())
+    }
+
+    /// Like `save_recording_wav` but encodes via `chunk_codec`'s Opus/FLAC
+    /// writers when `codec` requests compression, so the frontend can ask
+    /// for a small upload-ready file instead of full-size WAV. `opus_bitrate`
+    /// is only consulted for `ChunkCodec::Opus`. Falls back to WAV if Opus
+    /// encoding fails, same as `AudioChunker`'s chunk writer.
+    pub async fn save_recording_encoded(
+        &self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        channels: u16,
+        codec: ChunkCodec,
+        opus_bitrate: i32,
+        filename: String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        if codec == ChunkCodec::Wav {
+            return self.save_recording_wav(samples, sample_rate, channels, filename).await;
+        }
+
+        let app_data_dir = self.ensure_app_data_dir().await?;
+        let stem = Path::new(&filename)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| filename.clone());
+        let filepath = Path::new(&app_data_dir)
+            .join("recordings")
+            .join(format!("{}.{}", stem, codec.extension()));
+
+        if let Some(parent) = filepath.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        match codec {
+            ChunkCodec::Opus => {
+                if let Err(e) = chunk_codec::write_opus_chunk(&filepath, sample_rate, channels, &samples, opus_bitrate) {
+                    eprintln!("⚠️ Opus encode failed for recording ({}), falling back to WAV", e);
+                    return self.save_recording_wav(samples, sample_rate, channels, filename).await;
+                }
+            }
+            ChunkCodec::Flac => {
+                let flac_channels = channels.max(1) as usize;
+                let mono: Vec<f32> = if flac_channels <= 1 {
+                    samples
+                } else {
+                    samples.chunks(flac_channels).map(|f| f.iter().sum::<f32>() / f.len() as f32).collect()
+                };
+                chunk_codec::write_flac_chunk(&filepath, sample_rate, &mono)?;
+            }
+            ChunkCodec::Wav => unreachable!(),
+        }
+
+        Ok(filepath.to_string_lossy().to_string())
+    }
+
+    /// Parse a WAV file back into samples, normalizing PCM16 (or any
+    /// integer sub-format) down to `f32` in `[-1, 1]`; IEEE-float WAVs are
+    /// passed through as-is. Pairs with `save_recording_wav` for
+    /// playback/re-transcription.
+    pub async fn load_recording_wav(&self, filepath: String) -> Result<(Vec<f32>, u32, u16), Box<dyn std::error::Error>> {
+        let mut reader = hound::WavReader::open(&filepath)?;
+        let spec = reader.spec();
+
+        let samples: Vec<f32> = match spec.sample_format {
+            hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+            hound::SampleFormat::Int => {
+                let max = (1i64 << (spec.bits_per_sample - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|s| s.map(|v| v as f32 / max))
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        Ok((samples, spec.sample_rate, spec.channels))
+    }
+
     pub async fn list_recordings(&self) -> Result<Vec<FileInfo>, Box<dyn std::error::Error>> {
         let app_data_dir = self.ensure_app_data_dir().await?;
         let recordings_dir = Path::new(&app_data_dir).join("recordings");
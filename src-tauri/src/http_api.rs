@@ -0,0 +1,239 @@
+//! OpenAI `/v1/audio/transcriptions`-compatible HTTP endpoint wrapping
+//! `WhisperManager`, so existing OpenAI client code (and tools built
+//! against the OpenAI SDK) can point at this on-prem server unchanged.
+
+use crate::transcript_format::TranscriptFormat;
+use crate::whisper::{RoutingPolicy, WhisperManager};
+use anyhow::anyhow;
+use axum::{
+    extract::{Multipart, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use std::io::Cursor;
+use std::net::SocketAddr;
+
+/// Default bind port for `serve`, overridable via `WHISPER_HTTP_PORT`.
+pub const DEFAULT_PORT: u16 = 7878;
+
+#[derive(Clone)]
+struct ApiState {
+    // `WhisperManager` is cheap to clone (every field is already
+    // independently `Arc`-wrapped) - holding it directly instead of behind
+    // an outer `Mutex` means concurrent requests can run whisper.cpp
+    // inference in parallel instead of fully serializing on one lock.
+    whisper_manager: WhisperManager,
+}
+
+/// Wraps any handler failure as an OpenAI-style `{"error": {"message": ...}}`
+/// JSON body.
+struct ApiError(anyhow::Error);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({ "error": { "message": self.0.to_string() } });
+        (StatusCode::BAD_REQUEST, Json(body)).into_response()
+    }
+}
+
+impl<E> From<E> for ApiError
+where
+    E: Into<anyhow::Error>,
+{
+    fn from(err: E) -> Self {
+        ApiError(err.into())
+    }
+}
+
+/// Build the router exposing the transcription endpoint.
+pub fn router(whisper_manager: WhisperManager) -> Router {
+    Router::new()
+        .route("/v1/audio/transcriptions", post(transcriptions))
+        .with_state(ApiState { whisper_manager })
+}
+
+/// Bind and serve the OpenAI-compatible endpoint on `addr` until the process
+/// exits or the listener errors.
+pub async fn serve(whisper_manager: WhisperManager, addr: SocketAddr) -> anyhow::Result<()> {
+    let app = router(whisper_manager);
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    println!("🌐 OpenAI-compatible Whisper endpoint listening on http://{}", addr);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+struct UploadedFile {
+    filename: String,
+    bytes: Vec<u8>,
+}
+
+/// Parses the `multipart/form-data` body of an OpenAI transcription
+/// request: the required `file` field plus the optional `model` and
+/// `response_format` fields (any other field is read and discarded).
+async fn transcriptions(
+    State(state): State<ApiState>,
+    mut multipart: Multipart,
+) -> Result<Response, ApiError> {
+    let mut file: Option<UploadedFile> = None;
+    let mut model: Option<String> = None;
+    let mut response_format = "json".to_string();
+
+    while let Some(field) = multipart.next_field().await? {
+        match field.name().unwrap_or("") {
+            "file" => {
+                let filename = field.file_name().unwrap_or("audio").to_string();
+                let bytes = field.bytes().await?.to_vec();
+                file = Some(UploadedFile { filename, bytes });
+            }
+            "model" => model = Some(field.text().await?),
+            "response_format" => response_format = field.text().await?,
+            _ => {
+                let _ = field.bytes().await;
+            }
+        }
+    }
+
+    let file = file.ok_or_else(|| ApiError(anyhow!("missing required 'file' field")))?;
+    let (audio, sample_rate) = decode_uploaded_audio(&file.filename, &file.bytes)?;
+
+    // Clone (cheap - see `ApiState`) instead of locking: a client-requested
+    // `model` is resolved to a one-off `RoutingPolicy::Pinned(index)` below
+    // rather than mutating shared `current_service` state, so two concurrent
+    // requests naming different models can't interleave and transcribe each
+    // other's audio against the wrong backend.
+    let manager = state.whisper_manager.clone();
+    let service_index = match &model {
+        Some(model_name) => service_index_for_model(&manager, model_name).await,
+        None => None,
+    };
+
+    let response = match response_format.as_str() {
+        "text" => {
+            let text = transcribe_pinned(&manager, &audio, sample_rate, service_index).await?;
+            text.into_response()
+        }
+        "json" => {
+            let text = transcribe_pinned(&manager, &audio, sample_rate, service_index).await?;
+            Json(serde_json::json!({ "text": text })).into_response()
+        }
+        "verbose_json" => {
+            let json = manager
+                .transcribe_to_format(&audio, sample_rate, TranscriptFormat::VerboseJson, service_index)
+                .await?;
+            ([(header::CONTENT_TYPE, "application/json")], json).into_response()
+        }
+        "srt" => {
+            let srt = manager
+                .transcribe_to_format(&audio, sample_rate, TranscriptFormat::Srt, service_index)
+                .await?;
+            ([(header::CONTENT_TYPE, "application/x-subrip")], srt).into_response()
+        }
+        "vtt" => {
+            let vtt = manager
+                .transcribe_to_format(&audio, sample_rate, TranscriptFormat::Vtt, service_index)
+                .await?;
+            ([(header::CONTENT_TYPE, "text/vtt")], vtt).into_response()
+        }
+        other => return Err(ApiError(anyhow!("unsupported response_format '{}'", other))),
+    };
+
+    Ok(response)
+}
+
+/// Transcribe against `service_index` if the client named a known `model`,
+/// otherwise fall back to `transcribe_balanced`'s own routing policy - both
+/// paths avoid touching shared `current_service`/`routing_policy` state so
+/// concurrent requests can't race each other.
+async fn transcribe_pinned(
+    manager: &WhisperManager,
+    audio: &[f32],
+    sample_rate: u32,
+    service_index: Option<usize>,
+) -> anyhow::Result<String> {
+    match service_index {
+        Some(index) => {
+            manager
+                .transcribe_with_policy(audio, sample_rate, RoutingPolicy::Pinned(index))
+                .await
+        }
+        None => manager.transcribe_balanced(audio, sample_rate).await,
+    }
+}
+
+/// Finds the registered service index whose `list_services` `model_name`
+/// matches the client-requested `model`, if any.
+async fn service_index_for_model(manager: &WhisperManager, model: &str) -> Option<usize> {
+    manager.list_services().await.into_iter().find_map(|info| {
+        let matches = info.get("model_name").and_then(|v| v.as_str()) == Some(model);
+        matches
+            .then(|| info.get("index").and_then(|v| v.as_u64()))
+            .flatten()
+            .map(|i| i as usize)
+    })
+}
+
+/// Decodes an uploaded audio file into mono f32 PCM, dispatching on the
+/// filename extension (wav or mp3 per the OpenAI API's accepted formats).
+fn decode_uploaded_audio(filename: &str, bytes: &[u8]) -> anyhow::Result<(Vec<f32>, u32)> {
+    if filename.to_lowercase().ends_with(".mp3") {
+        decode_mp3(bytes)
+    } else {
+        decode_wav(bytes)
+    }
+}
+
+fn decode_wav(bytes: &[u8]) -> anyhow::Result<(Vec<f32>, u32)> {
+    let mut reader = hound::WavReader::new(Cursor::new(bytes))?;
+    let spec = reader.spec();
+
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+        hound::SampleFormat::Int => {
+            let max = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / max))
+                .collect::<Result<_, _>>()?
+        }
+    };
+
+    Ok((downmix_to_mono(samples, spec.channels), spec.sample_rate))
+}
+
+fn decode_mp3(bytes: &[u8]) -> anyhow::Result<(Vec<f32>, u32)> {
+    let mut decoder = minimp3::Decoder::new(Cursor::new(bytes));
+    let mut samples = Vec::new();
+    let mut sample_rate = 0u32;
+    let mut channels = 1usize;
+
+    loop {
+        match decoder.next_frame() {
+            Ok(frame) => {
+                sample_rate = frame.sample_rate as u32;
+                channels = frame.channels;
+                samples.extend(frame.data.iter().map(|s| *s as f32 / i16::MAX as f32));
+            }
+            Err(minimp3::Error::Eof) => break,
+            Err(e) => return Err(anyhow!("mp3 decode error: {:?}", e)),
+        }
+    }
+
+    if sample_rate == 0 {
+        return Err(anyhow!("no audio frames decoded from mp3 upload"));
+    }
+
+    Ok((downmix_to_mono(samples, channels as u16), sample_rate))
+}
+
+fn downmix_to_mono(samples: Vec<f32>, channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return samples;
+    }
+    let channels = channels as usize;
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
@@ -16,10 +16,16 @@ mod performance;
 mod error;
 mod coordinator;
 mod plugins;
+mod http_api;
+mod transcript_format;
+mod chunk_codec;
+mod denoise;
+mod config;
+mod model_manager;
 
 // Plugin module
 use plugins::audio_capture as audio_capture_plugin;
-use audio::{AudioCapture, AudioDevice};
+use audio::{AudioBufferingConfig, AudioCapture, AudioDevice, HostInfo, JitterBufferStats};
 use multi_audio::{MultiSourceAudioCapture, MultiAudioConfig, AudioSource};
 use whisper::{LocalWhisperService, WhisperManager, WhisperConfig, WhisperQuality, SupportedLanguages, ModelInfo, WhisperDevice, SpeakerSegment};
 use windows::WindowManager;
@@ -31,7 +37,7 @@ use performance::PerformanceMonitor;
 use env::load_environment;
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tauri::Manager;
+use tauri::{Manager, Emitter};
 use std::path::PathBuf;
 use std::sync::Arc as StdArc;
 use tokio::sync::Mutex as TokioMutex;
@@ -39,37 +45,72 @@ use plugins::audio_capture::ChunkEvent;
 
 #[tauri::command]
 async fn get_audio_devices(
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<Vec<AudioDevice>, String> {
-    let capture = audio_capture.lock().await;
-    capture.enumerate_devices()
+    let capture = audio_capture.inner();
+    capture.enumerate_devices().await
         .map_err(|e| format!("Failed to enumerate devices: {}", e))
 }
 
+#[tauri::command]
+async fn list_audio_hosts() -> Result<Vec<HostInfo>, String> {
+    Ok(AudioCapture::list_hosts())
+}
+
+#[tauri::command]
+async fn set_audio_host(
+    host_id: String,
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
+) -> Result<(), String> {
+    let id = AudioCapture::list_hosts()
+        .into_iter()
+        .find(|h| h.id == host_id)
+        .ok_or_else(|| format!("Unknown audio host: {}", host_id))?;
+    let host_id = cpal::available_hosts()
+        .into_iter()
+        .find(|h| format!("{:?}", h) == id.id)
+        .ok_or_else(|| format!("Unknown audio host: {}", host_id))?;
+
+    let capture = audio_capture.inner();
+    capture.set_host(host_id).await
+        .map_err(|e| format!("Failed to switch audio host: {}", e))
+}
+
 #[tauri::command]
 async fn start_audio_capture(
     device_id: String,
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<(), String> {
-    let mut capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     capture.start_capture(device_id).await
         .map_err(|e| format!("Failed to start capture: {}", e))
 }
 
+#[tauri::command]
+async fn start_aggregate_audio_capture(
+    mic_id: String,
+    system_id: String,
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
+) -> Result<(), String> {
+    let capture = audio_capture.inner();
+    capture.start_aggregate_capture(mic_id, system_id).await
+        .map_err(|e| format!("Failed to start aggregate capture: {}", e))
+}
+
 #[tauri::command]
 async fn stop_audio_capture(
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<(), String> {
-    let mut capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     capture.stop_capture().await
         .map_err(|e| format!("Failed to stop capture: {}", e))
 }
 
 #[tauri::command]
 async fn get_audio_data(
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<Vec<f32>, String> {
-    let capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     capture.get_audio_data().await
         .map_err(|e| format!("Failed to get audio data: {}", e))
 }
@@ -175,6 +216,57 @@ async fn list_recording_files(
         .map_err(|e| format!("Failed to list recordings: {}", e))
 }
 
+#[tauri::command]
+async fn save_recording_wav(
+    samples: Vec<f32>,
+    sample_rate: u32,
+    channels: u16,
+    filename: String,
+    fs_manager: tauri::State<'_, Arc<Mutex<FileSystemManager>>>
+) -> Result<String, String> {
+    let manager = fs_manager.lock().await;
+    manager.save_recording_wav(samples, sample_rate, channels, filename).await
+        .map_err(|e| format!("Failed to save recording WAV: {}", e))
+}
+
+/// Like `save_recording_wav` but compresses via Opus/FLAC when `codec`
+/// requests it (`"wav"` | `"opus"` | `"flac"`), for callers that want a
+/// smaller file ready for on-prem upload without a separate transcode step.
+/// `opus_bitrate` (bits/sec) is only consulted for `"opus"` and defaults to
+/// `chunk_codec::DEFAULT_OPUS_BITRATE` when omitted.
+#[tauri::command]
+async fn save_recording_encoded(
+    samples: Vec<f32>,
+    sample_rate: u32,
+    channels: u16,
+    codec: String,
+    opus_bitrate: Option<i32>,
+    filename: String,
+    fs_manager: tauri::State<'_, Arc<Mutex<FileSystemManager>>>
+) -> Result<String, String> {
+    let codec = match codec.to_lowercase().as_str() {
+        "wav" => chunk_codec::ChunkCodec::Wav,
+        "opus" => chunk_codec::ChunkCodec::Opus,
+        "flac" => chunk_codec::ChunkCodec::Flac,
+        other => return Err(format!("unsupported chunk codec '{}'", other)),
+    };
+    let manager = fs_manager.lock().await;
+    manager
+        .save_recording_encoded(samples, sample_rate, channels, codec, opus_bitrate.unwrap_or(chunk_codec::DEFAULT_OPUS_BITRATE), filename)
+        .await
+        .map_err(|e| format!("Failed to save encoded recording: {}", e))
+}
+
+#[tauri::command]
+async fn load_recording_wav(
+    filepath: String,
+    fs_manager: tauri::State<'_, Arc<Mutex<FileSystemManager>>>
+) -> Result<(Vec<f32>, u32, u16), String> {
+    let manager = fs_manager.lock().await;
+    manager.load_recording_wav(filepath).await
+        .map_err(|e| format!("Failed to load recording WAV: {}", e))
+}
+
 #[tauri::command]
 async fn get_performance_metrics(
     perf_monitor: tauri::State<'_, Arc<Mutex<PerformanceMonitor>>>
@@ -186,35 +278,65 @@ async fn get_performance_metrics(
 
 #[tauri::command]
 async fn is_recording(
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<bool, String> {
-    let capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     Ok(capture.is_recording())
 }
 
 #[tauri::command]
 async fn get_active_audio_devices(
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<Vec<String>, String> {
-    let capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     Ok(capture.get_active_devices().await)
 }
 
 #[tauri::command]
 async fn get_audio_buffer_size(
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<usize, String> {
-    let capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     capture.get_audio_buffer_size().await
         .map_err(|e| format!("Failed to get buffer size: {}", e))
 }
 
+#[tauri::command]
+async fn get_audio_buffering_stats(
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
+) -> Result<JitterBufferStats, String> {
+    let capture = audio_capture.inner();
+    Ok(capture.get_buffering_stats().await)
+}
+
+#[tauri::command]
+async fn set_audio_buffering_config(
+    target_latency_ms: u32,
+    batch_ms: u32,
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
+) -> Result<(), String> {
+    let capture = audio_capture.inner();
+    capture.set_buffering_config(AudioBufferingConfig { target_latency_ms, batch_ms });
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_audio_data_resampled(
+    target_rate: u32,
+    target_channels: u16,
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
+) -> Result<Vec<f32>, String> {
+    let capture = audio_capture.inner();
+    capture.get_audio_data_resampled(target_rate, target_channels).await
+        .map_err(|e| format!("Failed to resample audio data: {}", e))
+}
+
 #[tauri::command]
 async fn get_audio_data_chunk(
     max_samples: usize,
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<Vec<f32>, String> {
-    let capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     capture.get_audio_data_chunk(max_samples).await
         .map_err(|e| format!("Failed to get audio chunk: {}", e))
 }
@@ -222,9 +344,9 @@ async fn get_audio_data_chunk(
 #[tauri::command]
 async fn stop_device_capture(
     device_id: String,
-    audio_capture: tauri::State<'_, Arc<Mutex<AudioCapture>>>
+    audio_capture: tauri::State<'_, Arc<AudioCapture>>
 ) -> Result<(), String> {
-    let mut capture = audio_capture.lock().await;
+    let capture = audio_capture.inner();
     capture.stop_device_capture(&device_id).await
         .map_err(|e| format!("Failed to stop device capture: {}", e))
 }
@@ -286,19 +408,98 @@ async fn get_multi_audio_status(
     Ok(capture.get_status().await)
 }
 
+#[tauri::command]
+async fn set_source_gain(
+    source_id: String,
+    gain: f32,
+    multi_audio: tauri::State<'_, Arc<Mutex<MultiSourceAudioCapture>>>
+) -> Result<(), String> {
+    let capture = multi_audio.lock().await;
+    capture.set_source_gain(&source_id, gain).await
+        .map_err(|e| format!("Failed to set source gain: {}", e))
+}
+
+#[tauri::command]
+async fn set_source_muted(
+    source_id: String,
+    muted: bool,
+    multi_audio: tauri::State<'_, Arc<Mutex<MultiSourceAudioCapture>>>
+) -> Result<(), String> {
+    let capture = multi_audio.lock().await;
+    capture.set_source_muted(&source_id, muted).await
+        .map_err(|e| format!("Failed to set source muted: {}", e))
+}
+
+#[tauri::command]
+async fn set_source_solo(
+    source_id: String,
+    solo: bool,
+    multi_audio: tauri::State<'_, Arc<Mutex<MultiSourceAudioCapture>>>
+) -> Result<(), String> {
+    let capture = multi_audio.lock().await;
+    capture.set_source_solo(&source_id, solo).await
+        .map_err(|e| format!("Failed to set source solo: {}", e))
+}
+
+#[tauri::command]
+async fn export_source_audio_wav(
+    source_id: String,
+    path: String,
+    multi_audio: tauri::State<'_, Arc<Mutex<MultiSourceAudioCapture>>>
+) -> Result<(), String> {
+    let capture = multi_audio.lock().await;
+    capture.export_source_wav(&source_id, &path).await
+        .map_err(|e| format!("Failed to export source audio: {}", e))
+}
+
+#[tauri::command]
+async fn export_mixed_audio_wav(
+    path: String,
+    multi_audio: tauri::State<'_, Arc<Mutex<MultiSourceAudioCapture>>>
+) -> Result<(), String> {
+    let capture = multi_audio.lock().await;
+    capture.export_mixed_wav(&path).await
+        .map_err(|e| format!("Failed to export mixed audio: {}", e))
+}
+
 #[tauri::command]
 async fn initialize_whisper(
+    app: tauri::AppHandle,
     whisper_manager: tauri::State<'_, Arc<Mutex<WhisperManager>>>
 ) -> Result<(), String> {
     let manager = whisper_manager.lock().await;
-    
-    // Add default Whisper service
-    let config = WhisperConfig::default();
-    let service = LocalWhisperService::new(config);
-    
+
+    // Add default Whisper service, applying whatever the user persisted
+    // last session (quality/language/noise reduction) instead of always
+    // starting from `WhisperConfig::default()`.
+    let persisted = config::load_config(&app);
+    let mut whisper_config = WhisperConfig::default();
+    whisper_config.quality = persisted.whisper_quality;
+    whisper_config.language = persisted.preferred_language;
+    whisper_config.noise_reduction_strength = persisted.noise_reduction_strength;
+
+    // Refuse to start until the model is actually on disk instead of
+    // silently kicking off a multi-gigabyte blocking download on first
+    // transcribe - callers provision the model explicitly via
+    // `download_model` first.
+    let model_manager = model_manager::ModelManager::new(app.clone()).map_err(|e| e.to_string())?;
+    if !model_manager.is_installed(&whisper_config.model_name) {
+        return Err(format!(
+            "Whisper model '{}' is not installed - call download_model to fetch it first",
+            whisper_config.model_name
+        ));
+    }
+    whisper_config.model_path = Some(
+        model_manager
+            .model_path(&whisper_config.model_name)
+            .map_err(|e| e.to_string())?,
+    );
+
+    let service = LocalWhisperService::new(whisper_config);
+
     manager.add_service(service).await
         .map_err(|e| format!("Failed to initialize Whisper: {}", e))?;
-    
+
     Ok(())
 }
 
@@ -313,6 +514,58 @@ async fn transcribe_audio_data(
         .map_err(|e| format!("Failed to transcribe audio: {}", e))
 }
 
+/// Start a live-captioning session for a just-started recording. Pairs
+/// with `feed_transcript_stream`/`end_transcript_stream`; the floating
+/// recorder calls this once when recording begins.
+#[tauri::command]
+async fn begin_transcript_stream(
+    session_id: String,
+    sample_rate: u32,
+    whisper_manager: tauri::State<'_, Arc<Mutex<WhisperManager>>>,
+) -> Result<(), String> {
+    let manager = whisper_manager.lock().await;
+    manager.begin_stream(session_id, sample_rate).await
+        .map_err(|e| format!("Failed to begin transcript stream: {}", e))
+}
+
+/// Feed newly-captured audio into `session_id`'s live-captioning window.
+/// Emits a `transcript:partial` event per `TranscriptEvent` produced by
+/// this feed (usually none, since `WhisperManager::feed_stream` only
+/// re-decodes once enough new audio has accumulated).
+#[tauri::command]
+async fn feed_transcript_stream(
+    app: tauri::AppHandle,
+    session_id: String,
+    audio_data: Vec<f32>,
+    whisper_manager: tauri::State<'_, Arc<Mutex<WhisperManager>>>,
+) -> Result<(), String> {
+    let manager = whisper_manager.lock().await;
+    let events = manager.feed_stream(&session_id, audio_data).await
+        .map_err(|e| format!("Failed to feed transcript stream: {}", e))?;
+    for event in events {
+        let _ = app.emit("transcript:partial", &event);
+    }
+    Ok(())
+}
+
+/// Finalize `session_id`'s live-captioning session when recording stops,
+/// flushing the remaining window as `Final` events and dropping the
+/// session's state.
+#[tauri::command]
+async fn end_transcript_stream(
+    app: tauri::AppHandle,
+    session_id: String,
+    whisper_manager: tauri::State<'_, Arc<Mutex<WhisperManager>>>,
+) -> Result<(), String> {
+    let manager = whisper_manager.lock().await;
+    let events = manager.end_stream(&session_id).await
+        .map_err(|e| format!("Failed to end transcript stream: {}", e))?;
+    for event in events {
+        let _ = app.emit("transcript:partial", &event);
+    }
+    Ok(())
+}
+
 #[tauri::command]
 async fn get_whisper_models(
     whisper_manager: tauri::State<'_, Arc<Mutex<WhisperManager>>>
@@ -441,7 +694,7 @@ async fn get_speaker_segments(
 }
 
 fn main() {
-    let audio_capture = Arc::new(Mutex::new(AudioCapture::new().unwrap()));
+    let audio_capture = Arc::new(AudioCapture::new().unwrap());
     let window_manager = Arc::new(Mutex::new(WindowManager::new()));
     let perf_monitor = Arc::new(Mutex::new(PerformanceMonitor::new()));
     let ipc_bridge = Arc::new(Mutex::new(IPCBridge::new()));
@@ -458,8 +711,13 @@ fn main() {
     let chunk_secs = 10u64;
     let mut audio_chunker = audio_capture_plugin::AudioChunker::new(sample_rate, chunk_secs);
 
+    // OpenAI-compatible HTTP endpoint, served alongside the Tauri app so
+    // existing OpenAI client code can point at this process unchanged.
+    let whisper_manager_http = whisper_manager.clone();
+    let whisper_manager_stream = whisper_manager.clone();
+
     tauri::Builder::default()
-        .setup(|app| {
+        .setup(move |app| {
             // Assign app handle to audio chunker after app build
             audio_chunker.set_app_handle(app.handle().clone());
             app.manage(StdArc::new(TokioMutex::new(audio_chunker)));
@@ -469,15 +727,56 @@ fn main() {
             let coord_state = StdArc::new(TokioMutex::new(coord));
             app.manage(coord_state.clone());
 
+            tauri::async_runtime::spawn(async move {
+                let port: u16 = std::env::var("WHISPER_HTTP_PORT")
+                    .ok()
+                    .and_then(|p| p.parse().ok())
+                    .unwrap_or(http_api::DEFAULT_PORT);
+                let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+                // `WhisperManager` is cheap to clone (its fields are already
+                // `Arc`s internally), so this lock is only held long enough to
+                // obtain an owned copy - `serve` itself never holds a lock
+                // across a request, letting concurrent HTTP requests run
+                // inference in parallel.
+                let whisper_manager_http = whisper_manager_http.lock().await.clone();
+                if let Err(e) = http_api::serve(whisper_manager_http, addr).await {
+                    eprintln!("❌ OpenAI-compatible HTTP server failed: {}", e);
+                }
+            });
+
             // Listen to audio:chunk and forward to coordinator
             let app_handle = app.handle().clone();
+            let emit_handle = app_handle.clone();
             app_handle.listen_global("audio:chunk", move |event| {
                 if let Some(payload) = event.payload() {
                     if let Ok(meta) = serde_json::from_str::<ChunkEvent>(payload) {
                         let coord_state = coord_state.clone();
+                        let meta_for_coord = meta.clone();
                         tauri::async_runtime::spawn(async move {
                             let coordinator = coord_state.lock().await;
-                            coordinator.handle_chunk(&meta.session_id, &meta.path, meta.start_ms, meta.end_ms).await;
+                            coordinator.handle_chunk(&meta_for_coord.session_id, &meta_for_coord.path, meta_for_coord.start_ms, meta_for_coord.end_ms).await;
+                        });
+
+                        // Also feed the chunk's audio into the session's
+                        // live-captioning window (if `begin_transcript_stream`
+                        // was called for this session), so partials keep
+                        // arriving chunk-by-chunk instead of only once the
+                        // recording stops.
+                        let whisper_manager_stream = whisper_manager_stream.clone();
+                        let emit_handle = emit_handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let path = std::path::PathBuf::from(&meta.path);
+                            let (samples, _rate) = match chunk_codec::decode_chunk_samples(&path) {
+                                Ok(decoded) => decoded,
+                                Err(_) => return,
+                            };
+                            let samples: Vec<f32> = samples.iter().map(|s| *s as f32 / i16::MAX as f32).collect();
+                            let manager = whisper_manager_stream.lock().await;
+                            if let Ok(events) = manager.feed_stream(&meta.session_id, samples).await {
+                                for event in events {
+                                    let _ = emit_handle.emit("transcript:partial", &event);
+                                }
+                            }
                         });
                     }
                 }
@@ -494,13 +793,19 @@ fn main() {
         .manage(whisper_manager)
         .invoke_handler(tauri::generate_handler![
             get_audio_devices,
+            list_audio_hosts,
+            set_audio_host,
             start_audio_capture,
+            start_aggregate_audio_capture,
             stop_audio_capture,
             get_audio_data,
             is_recording,
             get_active_audio_devices,
             get_audio_buffer_size,
             get_audio_data_chunk,
+            get_audio_data_resampled,
+            get_audio_buffering_stats,
+            set_audio_buffering_config,
             stop_device_capture,
             show_floating_recorder,
             hide_floating_recorder,
@@ -513,17 +818,44 @@ fn main() {
             show_notification,
             save_recording_file,
             list_recording_files,
+            save_recording_wav,
+            save_recording_encoded,
+            load_recording_wav,
+            config::get_config,
+            config::update_config,
+            config::set_credentials,
+            config::clear_credentials,
+            model_manager::download_model,
+            model_manager::list_installed_models,
+            model_manager::delete_model,
             get_performance_metrics,
             // Diarizer helper
             whisper::diarize_wav_file,
+            // Range-based re-transcription
+            coordinator::retranscribe_range,
+            coordinator::retranscribe_range_blocking,
             // Audio capture plugin-style commands
             audio_capture_plugin::ac_get_devices,
             audio_capture_plugin::ac_start_mic,
             audio_capture_plugin::ac_start_system,
             audio_capture_plugin::ac_start_mix,
             audio_capture_plugin::ac_stop_all,
+            audio_capture_plugin::ac_pause,
+            audio_capture_plugin::ac_resume,
+            audio_capture_plugin::ac_set_chunk_secs,
+            audio_capture_plugin::ac_set_active_sources,
+            audio_capture_plugin::ac_set_mic_threshold,
+            audio_capture_plugin::ac_set_input_sensitivity,
+            audio_capture_plugin::ac_set_vad_threshold,
+            audio_capture_plugin::ac_get_performance_metrics,
+            audio_capture_plugin::ac_toggle_adaptive_chunking,
+            audio_capture_plugin::ac_set_chunk_format,
+            audio_capture_plugin::ac_set_sample_format,
             audio_capture_plugin::ac_get_active_session_info,
             audio_capture_plugin::ac_stop_and_finalize,
+            audio_capture_plugin::ac_list_recoverable,
+            audio_capture_plugin::ac_resume_session,
+            audio_capture_plugin::ac_finalize_session,
             // Phase 4: Multi-audio and Whisper commands
             discover_audio_sources,
             start_multi_recording,
@@ -531,8 +863,16 @@ fn main() {
             get_mixed_audio_data,
             get_source_audio_data,
             get_multi_audio_status,
+            set_source_gain,
+            set_source_muted,
+            set_source_solo,
+            export_source_audio_wav,
+            export_mixed_audio_wav,
             initialize_whisper,
             transcribe_audio_data,
+            begin_transcript_stream,
+            feed_transcript_stream,
+            end_transcript_stream,
             get_whisper_models,
             // Phase 5: Offline-first maximum accuracy commands
             transcribe_audio_with_language,
@@ -569,9 +909,18 @@ fn main() {
                 
                 if let Some(main_window) = app_handle_for_auth.get_webview_window("main") {
                     let auth_username = std::env::var("BASIC_AUTH_USERNAME").unwrap_or_else(|_| "myca".to_string());
-                    let auth_password = std::env::var("BASIC_AUTH_PASSWORD").unwrap_or_else(|_| "wj2YyxrJ4cqcXgCA".to_string());
+                    // No more shipped default password - look it up in the OS
+                    // keychain (set via the `set_credentials` command), falling
+                    // back to an env var for deployments that still set one,
+                    // and finally to an empty string if neither is configured.
+                    let auth_password = config::get_credentials(&auth_username)
+                        .or_else(|| std::env::var("BASIC_AUTH_PASSWORD").ok())
+                        .unwrap_or_default();
+                    if auth_password.is_empty() {
+                        eprintln!("⚠️ No basic-auth password configured for '{}' - call set_credentials to store one", auth_username);
+                    }
                     let api_base_url = std::env::var("VITE_API_BASE_URL").unwrap_or_else(|_| "http://95.111.244.159:8000/api".to_string());
-                    
+
                     // Get the actual computer username
                     let computer_username = std::env::var("USER")
                         .or_else(|_| std::env::var("USERNAME"))
@@ -0,0 +1,208 @@
+//! Explicit, resumable Whisper model provisioning. `whisper.rs`'s
+//! `LocalWhisperService::get_model_path` already knows how to fetch a
+//! missing ggml file, but it does so lazily on first transcribe (a
+//! multi-gigabyte blocking download with no progress reporting) via
+//! `hf_hub`'s own cache directory. `ModelManager` instead downloads into
+//! the app's own cache dir via a plain HTTP range request so an
+//! interrupted download resumes where it left off, verifies the result
+//! against a pinned checksum, and reports progress back to the frontend
+//! as `model:download-progress` events - the thing `check_offline_capabilities`
+//! implies exists but never actually wired up.
+
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Maps a `WhisperConfig::model_name` to the ggml quantization file
+/// `whisper.rs::LocalWhisperService::get_model_path` would also look for,
+/// published under the `ggerganov/whisper.cpp` Hugging Face repo.
+struct CatalogEntry {
+    model_name: &'static str,
+    ggml_filename: &'static str,
+    // Published sha256 of the ggml file, checked after download.
+    // TODO: pin real checksums from the model card - `None` entries still
+    // download fine, they just skip integrity verification in the
+    // meantime (same "be honest about what's not wired up yet" as
+    // `check_system_requirements`'s placeholder RAM check).
+    sha256: Option<&'static str>,
+}
+
+const MODEL_CATALOG: &[CatalogEntry] = &[
+    CatalogEntry { model_name: "openai/whisper-large-v3", ggml_filename: "ggml-large-v3.bin", sha256: None },
+    CatalogEntry { model_name: "openai/whisper-large-v2", ggml_filename: "ggml-large-v2.bin", sha256: None },
+    CatalogEntry { model_name: "openai/whisper-medium", ggml_filename: "ggml-medium.bin", sha256: None },
+    CatalogEntry { model_name: "openai/whisper-small", ggml_filename: "ggml-small.bin", sha256: None },
+];
+
+fn catalog_entry(model_name: &str) -> Result<&'static CatalogEntry> {
+    MODEL_CATALOG
+        .iter()
+        .find(|e| e.model_name == model_name)
+        .ok_or_else(|| anyhow!("no known ggml model for '{}'", model_name))
+}
+
+fn ggml_download_url(filename: &str) -> String {
+    format!("https://huggingface.co/ggerganov/whisper.cpp/resolve/main/{}", filename)
+}
+
+/// `model:download-progress` event payload: bytes done / total for the
+/// model currently being fetched by `download_model`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDownloadProgress {
+    pub model_name: String,
+    pub bytes_downloaded: u64,
+    pub total_bytes: u64,
+}
+
+pub struct ModelManager {
+    app: AppHandle,
+    models_dir: PathBuf,
+}
+
+impl ModelManager {
+    pub fn new(app: AppHandle) -> Result<Self> {
+        let models_dir = app.path().app_cache_dir()?.join("whisper_models");
+        std::fs::create_dir_all(&models_dir)?;
+        Ok(Self { app, models_dir })
+    }
+
+    /// Final on-disk path for `model_name`'s ggml file, whether or not it
+    /// has been downloaded yet.
+    pub fn model_path(&self, model_name: &str) -> Result<PathBuf> {
+        let entry = catalog_entry(model_name)?;
+        Ok(self.models_dir.join(entry.ggml_filename))
+    }
+
+    pub fn is_installed(&self, model_name: &str) -> bool {
+        self.model_path(model_name).map(|p| p.exists()).unwrap_or(false)
+    }
+
+    /// Every catalog model whose ggml file is already present in the
+    /// cache dir.
+    pub fn list_installed_models(&self) -> Vec<String> {
+        MODEL_CATALOG
+            .iter()
+            .filter(|e| self.models_dir.join(e.ggml_filename).exists())
+            .map(|e| e.model_name.to_string())
+            .collect()
+    }
+
+    pub fn delete_model(&self, model_name: &str) -> Result<()> {
+        let path = self.model_path(model_name)?;
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+        Ok(())
+    }
+
+    /// Download `model_name`'s ggml file into the cache dir. Resumes from
+    /// a `.part` file's existing length via an HTTP Range request if a
+    /// previous attempt was interrupted; a server that ignores the Range
+    /// header and sends a fresh `200 OK` restarts the part file rather
+    /// than appending a full response onto a partial one. Verifies the
+    /// finished file against `CatalogEntry::sha256` (when pinned) before
+    /// promoting it to its final name, and emits `model:download-progress`
+    /// as bytes arrive.
+    pub async fn download_model(&self, model_name: &str) -> Result<()> {
+        let entry = catalog_entry(model_name)?;
+        let final_path = self.models_dir.join(entry.ggml_filename);
+        if final_path.exists() {
+            return Ok(());
+        }
+
+        let part_path = final_path.with_extension("part");
+        let url = ggml_download_url(entry.ggml_filename);
+        let client = reqwest::Client::new();
+
+        let resume_from = std::fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+
+        let mut request = client.get(&url);
+        if resume_from > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("failed to start download of {}: {}", entry.ggml_filename, e))?;
+        if !response.status().is_success() {
+            return Err(anyhow!("download of {} failed with status {}", entry.ggml_filename, response.status()));
+        }
+
+        let resuming = resume_from > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+        let total_bytes = response.content_length().unwrap_or(0) + if resuming { resume_from } else { 0 };
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(!resuming)
+            .append(resuming)
+            .open(&part_path)?;
+
+        let mut downloaded = if resuming { resume_from } else { 0 };
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| anyhow!("download of {} interrupted: {}", entry.ggml_filename, e))?;
+            file.write_all(&chunk)?;
+            downloaded += chunk.len() as u64;
+            let _ = self.app.emit(
+                "model:download-progress",
+                &ModelDownloadProgress {
+                    model_name: model_name.to_string(),
+                    bytes_downloaded: downloaded,
+                    total_bytes,
+                },
+            );
+        }
+        drop(file);
+
+        if let Some(expected) = entry.sha256 {
+            let actual = Self::sha256_file(&part_path)?;
+            if actual != expected {
+                let _ = std::fs::remove_file(&part_path);
+                return Err(anyhow!(
+                    "checksum mismatch for {}: expected {}, got {}",
+                    entry.ggml_filename, expected, actual
+                ));
+            }
+        }
+
+        std::fs::rename(&part_path, &final_path)?;
+        Ok(())
+    }
+
+    fn sha256_file(path: &PathBuf) -> Result<String> {
+        let mut file = std::fs::File::open(path)?;
+        let mut hasher = Sha256::new();
+        let mut buf = [0u8; 65536];
+        loop {
+            let n = file.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
+
+#[tauri::command]
+pub async fn download_model(app: AppHandle, model_name: String) -> Result<(), String> {
+    let manager = ModelManager::new(app).map_err(|e| e.to_string())?;
+    manager.download_model(&model_name).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_installed_models(app: AppHandle) -> Result<Vec<String>, String> {
+    let manager = ModelManager::new(app).map_err(|e| e.to_string())?;
+    Ok(manager.list_installed_models())
+}
+
+#[tauri::command]
+pub async fn delete_model(app: AppHandle, model_name: String) -> Result<(), String> {
+    let manager = ModelManager::new(app).map_err(|e| e.to_string())?;
+    manager.delete_model(&model_name).map_err(|e| e.to_string())
+}
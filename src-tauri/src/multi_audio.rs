@@ -3,10 +3,12 @@ use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::collections::{HashMap, HashSet};
 use anyhow::{anyhow, Result};
-use futures::executor::block_on;
+use ringbuf::{HeapRb, HeapProd, HeapCons, traits::{Producer, Consumer, Split, Observer}};
+use realfft::RealFftPlanner;
+
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AudioSource {
@@ -16,6 +18,23 @@ pub struct AudioSource {
     pub channels: u16,
     pub sample_rate: u32,
     pub is_active: bool,
+    /// Samples evicted by the configured `OverrunPolicy` because this
+    /// source's ring buffer filled up - see [`MultiSourceAudioCapture::get_source_stats`].
+    pub dropped_samples: u64,
+    /// Per-source gain multiplier applied in `mix_audio_sources`, so callers
+    /// can balance e.g. a quiet mic against a loud system loopback.
+    pub gain: f32,
+    /// Excluded from the mix entirely, regardless of `gain` or `solo` - see
+    /// [`MultiSourceAudioCapture::set_source_muted`].
+    pub muted: bool,
+    /// When any source is soloed, `mix_audio_sources` mixes only soloed
+    /// sources (muted ones still excluded) instead of every active one -
+    /// see [`MultiSourceAudioCapture::set_source_solo`].
+    pub solo: bool,
+    /// The terminal [`CaptureError`] (as a display string) that killed this
+    /// source's stream mid-recording, if any - set from the cpal error
+    /// callback via `source_faults`, surfaced by `get_source_stats`.
+    pub last_error: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -33,6 +52,37 @@ pub struct MultiAudioConfig {
     pub buffer_size: usize,
     pub max_sources: usize,
     pub mix_output: bool,
+    /// Per-source ring buffer capacity, in seconds of target-rate audio -
+    /// bounds how much a slow consumer (`get_source_audio`/`mix_audio_sources`)
+    /// can fall behind before `overrun_policy` kicks in, so a long recording
+    /// can't grow a source's buffer without limit.
+    pub ring_buffer_seconds: f32,
+    /// What happens when a source's ring buffer fills up because the
+    /// consumer fell behind.
+    pub overrun_policy: OverrunPolicy,
+    /// Whether to gate each source through `SourceVad` before its audio
+    /// reaches the ring buffer - silence/background-noise frames are
+    /// zeroed instead of summed into the mix, so a hissy or idle source
+    /// doesn't drown out whoever's actually talking.
+    pub vad_enabled: bool,
+    /// How far a frame's RMS energy must clear the adapted noise floor
+    /// (`energy > floor * vad_threshold`) to count as speech.
+    pub vad_threshold: f32,
+    /// How long, in milliseconds, a source stays flagged active after its
+    /// last frame over threshold, so a brief dip between syllables doesn't
+    /// chop word endings.
+    pub vad_hangover_ms: f32,
+    /// Whether to run each source through `SpectralDenoiser` before it
+    /// reaches the ring buffer - FFT-domain spectral subtraction for steady
+    /// background noise (fan/HVAC hum) ahead of VAD and mixing.
+    pub denoise_enabled: bool,
+    /// How aggressively to subtract the estimated noise-floor magnitude
+    /// from each frequency bin (`alpha` in `mag' = max(mag - alpha*noise_mag, beta*mag)`).
+    pub denoise_alpha: f32,
+    /// Floor, as a fraction of a bin's original magnitude, below which
+    /// subtraction never pushes it - without this, very low-SNR bins get
+    /// subtracted to near-zero and reconstruct as audible "musical noise".
+    pub denoise_beta: f32,
 }
 
 impl Default for MultiAudioConfig {
@@ -43,6 +93,704 @@ impl Default for MultiAudioConfig {
             buffer_size: 1024,
             max_sources: 4,
             mix_output: true,
+            ring_buffer_seconds: 30.0,
+            overrun_policy: OverrunPolicy::DropOldest,
+            vad_enabled: true,
+            vad_threshold: 3.0,
+            vad_hangover_ms: 300.0,
+            denoise_enabled: false,
+            denoise_alpha: 2.0,
+            denoise_beta: 0.05,
+        }
+    }
+}
+
+/// Controls what `push_with_policy` does when a source's ring buffer is full
+/// and new audio arrives - the same policy choice ALSA/PulseAudio make for a
+/// stalled reader, just exposed here instead of hardcoded.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum OverrunPolicy {
+    /// Evict the oldest queued samples to make room - freshest audio wins,
+    /// matching a live capture where stale backlog is worse than a gap.
+    DropOldest,
+    /// Leave the queued backlog alone and discard (part of) the incoming
+    /// audio instead - preserves ordering of whatever was already captured,
+    /// at the cost of losing the newest samples.
+    DropNewest,
+}
+
+/// Recoverable/classifiable audio-capture failures, replacing flat
+/// `anyhow!` strings so callers (and `get_source_stats`, once a stream dies
+/// mid-recording) can tell *why* a source failed instead of just that it
+/// did - e.g. to retry a vanished device, prompt for microphone permission,
+/// or fall back to another source. Still converts into `anyhow::Result` via
+/// the blanket `std::error::Error` -> `anyhow::Error` impl, so existing
+/// `?`-based callers are unaffected.
+#[derive(Debug, Clone)]
+pub enum CaptureError {
+    /// `start_multi_recording` was called while a recording was already in progress.
+    AlreadyRecording,
+    /// No matching device could be found (vanished, unplugged, or never existed).
+    DeviceNotFound(String),
+    /// The device doesn't support a sample format/layout we know how to read.
+    UnsupportedFormat(String),
+    /// Opening or starting the underlying cpal stream failed.
+    StreamBuildFailed(String),
+    /// Sources were requested, but none of them started successfully.
+    NoSourcesStarted,
+    /// The OS denied access to the device (e.g. missing microphone permission).
+    PermissionDenied(String),
+    /// `add_source` was asked to re-add a source whose cpal stream was
+    /// already opened (and leaked - see `push_with_policy`'s doc comment)
+    /// earlier this session, most recently by `remove_source`. Re-opening it
+    /// would leak another stream, so this is refused rather than silently
+    /// growing unbounded OS stream/thread usage across toggle cycles.
+    AlreadyStartedOnce(String),
+}
+
+impl std::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureError::AlreadyRecording => write!(f, "already recording"),
+            CaptureError::DeviceNotFound(msg) => write!(f, "device not found: {}", msg),
+            CaptureError::UnsupportedFormat(msg) => write!(f, "unsupported format: {}", msg),
+            CaptureError::StreamBuildFailed(msg) => write!(f, "failed to build audio stream: {}", msg),
+            CaptureError::NoSourcesStarted => write!(f, "no sources started successfully"),
+            CaptureError::PermissionDenied(msg) => write!(f, "permission denied: {}", msg),
+            CaptureError::AlreadyStartedOnce(id) => write!(
+                f,
+                "source {} was already started and removed this session, and can't be safely re-added without leaking another audio stream",
+                id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl From<cpal::BuildStreamError> for CaptureError {
+    fn from(e: cpal::BuildStreamError) -> Self {
+        CaptureError::StreamBuildFailed(e.to_string())
+    }
+}
+
+impl From<cpal::PlayStreamError> for CaptureError {
+    fn from(e: cpal::PlayStreamError) -> Self {
+        CaptureError::StreamBuildFailed(e.to_string())
+    }
+}
+
+impl From<cpal::DefaultStreamConfigError> for CaptureError {
+    fn from(e: cpal::DefaultStreamConfigError) -> Self {
+        CaptureError::DeviceNotFound(e.to_string())
+    }
+}
+
+/// Catch-all for the handful of lower-level cpal errors (device enumeration,
+/// supported-config queries) that don't carry enough information to
+/// classify more precisely than "the stream couldn't be built".
+impl From<anyhow::Error> for CaptureError {
+    fn from(e: anyhow::Error) -> Self {
+        CaptureError::StreamBuildFailed(e.to_string())
+    }
+}
+
+/// Classifies a terminal cpal stream error (fired from the real-time audio
+/// thread, after the stream was already running) well enough to decide
+/// whether it's worth prompting the user to re-grant permission versus just
+/// logging a dead source.
+fn classify_stream_fault(err: &cpal::StreamError) -> CaptureError {
+    let msg = err.to_string();
+    let lower = msg.to_lowercase();
+    if lower.contains("permission") || lower.contains("denied") || lower.contains("access") {
+        CaptureError::PermissionDenied(msg)
+    } else {
+        CaptureError::StreamBuildFailed(msg)
+    }
+}
+
+/// One source's lock-free SPSC ring buffer. The cpal callback is the sole
+/// producer; `mix_audio_sources`/`get_source_audio` are the sole consumer.
+/// Both ends are additionally wrapped in a plain (non-async) `Mutex` purely
+/// so the realtime callback can also trim the *front* of the buffer under
+/// overrun - an ordinary bounded push only ever rejects the *incoming* tail,
+/// which is the wrong end to drop for a live capture.
+struct SourceBuffer {
+    producer: Arc<std::sync::Mutex<HeapProd<f32>>>,
+    consumer: Arc<std::sync::Mutex<HeapCons<f32>>>,
+    dropped_samples: Arc<AtomicU64>,
+    /// Whether this source is currently flagged as speaking by its
+    /// `SourceVad` gate - surfaced in `get_status`'s `is_active` map.
+    vad_active: Arc<AtomicBool>,
+    /// Total samples classified as speech by the VAD gate since this
+    /// source started recording - surfaced in `get_status`'s `speech_seconds` map.
+    speech_samples: Arc<AtomicU64>,
+    /// Most recent RMS/peak level for this source, as `f32::to_bits`, set in
+    /// `push_with_policy` on every real-time callback - the metering tick in
+    /// `spawn_audio_actor` reads these non-blockingly instead of draining
+    /// the ring buffer, which would steal samples meant for transcription.
+    level_rms_bits: Arc<AtomicU32>,
+    level_peak_bits: Arc<AtomicU32>,
+}
+
+/// Pushes `data` into a source's bounded ring buffer according to `policy`,
+/// so a consumer (`get_source_audio`/`mix_audio_sources`) that falls behind
+/// during a long recording caps memory instead of growing it without limit -
+/// the same stalled-reader problem ALSA/PulseAudio solve by picking an
+/// overrun policy rather than blocking the writer forever. Non-blocking
+/// (`try_lock`) on both ends so a contended reader never stalls the
+/// real-time audio callback; if contended, falls back to a plain push, which
+/// still can't grow memory unbounded since the ring buffer is capped.
+///
+/// Wakes `notify` once real-time audio actually landed in the buffer, so an
+/// async consumer (the `AudioChunker` actor in `plugins::audio_capture`) can
+/// `notified().await` instead of polling the buffer on a wall-clock timer -
+/// `notify_waiters` is fine here since there's only ever one such consumer.
+///
+/// When `paused` is set, samples are dropped here - before resampling,
+/// denoising or VAD even ran on them in the caller - rather than being
+/// pushed and gated further downstream. cpal's `Stream` handles are
+/// deliberately leaked (`std::mem::forget`'d) once `play()`'d, since they
+/// aren't `Send`-friendly to stash across the chunker actor's async
+/// boundaries, so there's no stream handle to call a real `.pause()` on;
+/// this is the earliest point after the real-time callback fires that a
+/// shared flag can intercept its output.
+fn push_with_policy(
+    policy: OverrunPolicy,
+    producer: &Arc<std::sync::Mutex<HeapProd<f32>>>,
+    consumer: &Arc<std::sync::Mutex<HeapCons<f32>>>,
+    dropped_samples: &Arc<AtomicU64>,
+    notify: &Arc<tokio::sync::Notify>,
+    paused: &Arc<AtomicBool>,
+    level_rms_bits: &Arc<AtomicU32>,
+    level_peak_bits: &Arc<AtomicU32>,
+    data: &[f32],
+) {
+    if data.is_empty() || paused.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let sum_sq: f32 = data.iter().map(|s| s * s).sum();
+    let rms = (sum_sq / data.len() as f32).sqrt();
+    let peak = data.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+    level_rms_bits.store(rms.to_bits(), Ordering::Relaxed);
+    level_peak_bits.store(peak.to_bits(), Ordering::Relaxed);
+
+    let mut prod = match producer.try_lock() {
+        Ok(prod) => prod,
+        Err(_) => return,
+    };
+
+    let vacant = prod.vacant_len();
+    if vacant >= data.len() {
+        prod.push_slice(data);
+        notify.notify_waiters();
+        return;
+    }
+
+    match policy {
+        OverrunPolicy::DropOldest => {
+            // Freshest audio matters more than stale backlog for a live
+            // capture, so evict from the front instead of rejecting the tail
+            // the way a plain bounded push would.
+            if let Ok(mut cons) = consumer.try_lock() {
+                let to_evict = data.len() - vacant;
+                let evicted = cons.pop_iter().take(to_evict).count();
+                dropped_samples.fetch_add(evicted as u64, Ordering::Relaxed);
+            }
+            prod.push_slice(data);
+        }
+        OverrunPolicy::DropNewest => {
+            // Leave the queued backlog alone and only push as much of the
+            // incoming chunk as still fits, discarding the rest.
+            let to_drop = data.len() - vacant;
+            dropped_samples.fetch_add(to_drop as u64, Ordering::Relaxed);
+            prod.push_slice(&data[..vacant]);
+        }
+    }
+    notify.notify_waiters();
+}
+
+/// Taps on each side of `SourceResampler`'s windowed-sinc kernel (32 taps
+/// total) - enough to band-limit the interpolation for arbitrary
+/// source/target rate pairs without costing too much per output sample in a
+/// real-time callback.
+const RESAMPLER_HALF_TAPS: i64 = 16;
+
+/// Per-source windowed-sinc resampler, one instance per active stream,
+/// converting that source's native rate to `MultiAudioConfig::sample_rate`
+/// before its samples reach the shared ring buffer - `mix_audio_sources`
+/// sums raw samples across sources and has no idea they started life at
+/// different rates. Plain linear interpolation aliased badly on anything
+/// but near-identity rate ratios, so this follows the same band-limited
+/// approach as `audio.rs`'s `StreamingResampler` (Hann-windowed sinc
+/// evaluated directly at each output sample's fractional source position,
+/// with phase and history carried across calls), just applied per-source.
+struct SourceResampler {
+    /// Source-rate samples per target-rate sample (`source_rate / target_rate`).
+    step: f64,
+    /// Fractional source-sample read position for the *next* output sample,
+    /// relative to the start of the next `process()` call's input slice.
+    phase: f64,
+    /// Last `RESAMPLER_HALF_TAPS` samples of the previous chunk, so the sinc
+    /// kernel has history to draw on near a chunk boundary instead of
+    /// clicking at the seam.
+    history: Vec<f32>,
+}
+
+impl SourceResampler {
+    fn new(source_rate: u32, target_rate: u32) -> Self {
+        Self {
+            step: source_rate as f64 / target_rate.max(1) as f64,
+            phase: 0.0,
+            history: vec![0.0; RESAMPLER_HALF_TAPS as usize],
+        }
+    }
+
+    /// Hann-windowed sinc kernel value for integer tap offset `k`, given the
+    /// fractional output position `frac` within `[0, 1)` relative to tap 0 -
+    /// i.e. `sinc(frac - k)` tapered to zero at `|frac - k| == RESAMPLER_HALF_TAPS`.
+    fn kernel(frac: f64, k: i64) -> f64 {
+        let x = frac - k as f64;
+        let half = RESAMPLER_HALF_TAPS as f64;
+        if x.abs() >= half {
+            return 0.0;
+        }
+        let sinc = if x.abs() < 1e-9 {
+            1.0
+        } else {
+            (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+        };
+        let window = 0.5 + 0.5 * (std::f64::consts::PI * x / half).cos();
+        sinc * window
+    }
+
+    /// Carries the last `RESAMPLER_HALF_TAPS` samples of `input` (falling
+    /// back to whatever's left of the previous history for short chunks)
+    /// forward into `self.history` for the next `process()` call.
+    fn save_history(&mut self, input: &[f32]) {
+        let history_len = self.history.len();
+        if input.len() >= history_len {
+            self.history.copy_from_slice(&input[input.len() - history_len..]);
+        } else {
+            self.history.drain(0..input.len());
+            self.history.extend_from_slice(input);
+        }
+    }
+
+    fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        if input.is_empty() {
+            return Vec::new();
+        }
+        if (self.step - 1.0).abs() < f64::EPSILON {
+            // Identity case: skip the comparatively expensive sinc convolution.
+            self.save_history(input);
+            return input.to_vec();
+        }
+
+        let half = RESAMPLER_HALF_TAPS;
+        let history = &self.history;
+        let sample_at = |idx: i64| -> f32 {
+            if idx < 0 {
+                let hist_idx = history.len() as i64 + idx;
+                if hist_idx >= 0 { history[hist_idx as usize] } else { 0.0 }
+            } else if (idx as usize) < input.len() {
+                input[idx as usize]
+            } else {
+                0.0
+            }
+        };
+
+        let mut out = Vec::with_capacity((input.len() as f64 / self.step) as usize + 1);
+        let mut pos = self.phase;
+        loop {
+            let i = pos.floor() as i64;
+            // The kernel needs samples up to `i + half`; once that runs past
+            // the end of this chunk, stop and let `phase` carry the
+            // remainder into the next `process()` call.
+            if i + half >= input.len() as i64 {
+                break;
+            }
+            let frac = pos - i as f64;
+            let mut acc = 0.0f64;
+            for k in -(half - 1)..=half {
+                acc += sample_at(i + k) as f64 * Self::kernel(frac, k);
+            }
+            out.push(acc as f32);
+            pos += self.step;
+        }
+
+        self.phase = pos - input.len() as f64;
+        self.save_history(input);
+        out
+    }
+}
+
+/// 25ms analysis frame for `SourceVad` - short enough to not blur across a
+/// word boundary, long enough for a stable RMS estimate.
+const VAD_FRAME_MS: f32 = 25.0;
+
+/// Per-source energy-based voice-activity gate, run on each resampled chunk
+/// right before it reaches the ring buffer so a silent or background-noise
+/// source contributes nothing to `mix_audio_sources`. Tracks a slowly
+/// adapting noise-floor estimate - rising gently, falling quickly towards
+/// the observed minimum - and flags a frame as speech once its RMS energy
+/// clears `floor * threshold`, holding that flag for `hangover_frames` after
+/// the last frame over threshold so a brief dip between syllables doesn't
+/// get zeroed along with real silence. `active_flag`/`speech_samples` are
+/// shared with the async side (`get_status`) the same way `SourceBuffer`
+/// shares `dropped_samples` - this struct lives on cpal's real-time thread,
+/// so it can only hand results off through plain atomics.
+struct SourceVad {
+    frame_size: usize,
+    threshold: f32,
+    hangover_frames: u32,
+    floor: f32,
+    floor_initialized: bool,
+    hangover_remaining: u32,
+    active_flag: Arc<AtomicBool>,
+    speech_samples: Arc<AtomicU64>,
+}
+
+impl SourceVad {
+    fn new(
+        sample_rate: u32,
+        threshold: f32,
+        hangover_ms: f32,
+        active_flag: Arc<AtomicBool>,
+        speech_samples: Arc<AtomicU64>,
+    ) -> Self {
+        let frame_size = ((sample_rate as f32 * VAD_FRAME_MS / 1000.0).max(1.0)) as usize;
+        let hangover_frames = (hangover_ms / VAD_FRAME_MS).max(0.0) as u32;
+        Self {
+            frame_size,
+            threshold,
+            hangover_frames,
+            floor: 0.0,
+            floor_initialized: false,
+            hangover_remaining: 0,
+            active_flag,
+            speech_samples,
+        }
+    }
+
+    /// Marks this source active without running the gate - used when VAD is
+    /// disabled, so `get_status` doesn't report every source as perpetually
+    /// silent.
+    fn mark_active(&self) {
+        self.active_flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Zeroes out (in place) every frame classified as silence, and updates
+    /// the shared active flag/speech-sample counter. Operates on raw
+    /// interleaved samples - a frame boundary doesn't need to line up with a
+    /// channel boundary for an RMS energy estimate to be meaningful.
+    fn process(&mut self, samples: &mut [f32]) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let mut any_active = false;
+        let mut speech_this_call = 0u64;
+
+        for frame in samples.chunks_mut(self.frame_size.max(1)) {
+            let energy = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+
+            if !self.floor_initialized {
+                // Seed the floor from the first frame instead of 0.0 - the
+                // formula below can never climb off an exact zero (`min(0,
+                // positive)` is always 0), so it needs a non-degenerate
+                // starting point.
+                self.floor = energy;
+                self.floor_initialized = true;
+            } else {
+                self.floor = (self.floor * 1.02).min(0.95 * self.floor + 0.05 * energy);
+            }
+
+            let triggered = energy > self.floor * self.threshold;
+            if triggered {
+                self.hangover_remaining = self.hangover_frames;
+            } else if self.hangover_remaining > 0 {
+                self.hangover_remaining -= 1;
+            }
+
+            if triggered || self.hangover_remaining > 0 {
+                any_active = true;
+                speech_this_call += frame.len() as u64;
+            } else {
+                for sample in frame.iter_mut() {
+                    *sample = 0.0;
+                }
+            }
+        }
+
+        self.active_flag.store(any_active, Ordering::Relaxed);
+        if speech_this_call > 0 {
+            self.speech_samples.fetch_add(speech_this_call, Ordering::Relaxed);
+        }
+    }
+}
+
+/// FFT size for `SpectralDenoiser`'s analysis windows (~23ms at a 44.1kHz
+/// target rate) and the 50%-overlap hop that goes with it, same tradeoff as
+/// `VAD_FRAME_MS` - long enough for a stable spectral estimate, short enough
+/// to track a noise floor that drifts over a meeting.
+const DENOISE_FFT_SIZE: usize = 1024;
+const DENOISE_HOP_SIZE: usize = DENOISE_FFT_SIZE / 2;
+
+/// Per-source streaming spectral-subtraction denoiser: FFT-domain noise
+/// reduction for steady background noise (fan/HVAC hum) ahead of `SourceVad`
+/// and mixing. Runs overlapping Hann-windowed frames through `realfft`,
+/// subtracts a scaled noise-magnitude estimate per bin while keeping the
+/// original phase, and overlap-adds the inverse FFT back into a streaming
+/// output buffer - the same windowed-chunk-at-a-time shape as
+/// `SourceResampler`, just in the frequency domain. The noise estimate
+/// itself is gated by a simple energy-floor tracker (a second, independent
+/// copy of `SourceVad`'s floor idea) rather than `SourceVad` proper, since it
+/// has to start adapting before any of this source's audio has reached the
+/// real VAD stage.
+struct SpectralDenoiser {
+    alpha: f32,
+    beta: f32,
+    window: Vec<f32>,
+    cola_norm: f32,
+    r2c: Arc<dyn realfft::RealToComplex<f32>>,
+    c2r: Arc<dyn realfft::ComplexToReal<f32>>,
+    noise_mag: Vec<f32>,
+    noise_initialized: bool,
+    energy_floor: f32,
+    floor_initialized: bool,
+    input_carry: Vec<f32>,
+    output_overlap: Vec<f32>,
+}
+
+impl SpectralDenoiser {
+    fn new(alpha: f32, beta: f32) -> Self {
+        let mut planner = RealFftPlanner::<f32>::new();
+        let r2c = planner.plan_fft_forward(DENOISE_FFT_SIZE);
+        let c2r = planner.plan_fft_inverse(DENOISE_FFT_SIZE);
+        let num_bins = DENOISE_FFT_SIZE / 2 + 1;
+
+        // Square root of a *periodic* (not symmetric) Hann window: applied
+        // at both analysis and synthesis, the product is a periodic Hann,
+        // which is what makes 50% overlap-add reconstruct at a constant gain.
+        let window: Vec<f32> = (0..DENOISE_FFT_SIZE)
+            .map(|i| (0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / DENOISE_FFT_SIZE as f32).cos()).sqrt())
+            .collect();
+
+        // Constant-overlap-add normalization: sum of the analysis/synthesis
+        // window's square at hop-spaced offsets, so applying the window
+        // twice (analysis + synthesis) and overlap-adding doesn't scale the
+        // signal by the window's own energy.
+        let mut cola_norm = 0.0f32;
+        for i in 0..DENOISE_HOP_SIZE {
+            cola_norm += window[i] * window[i] + window[i + DENOISE_HOP_SIZE] * window[i + DENOISE_HOP_SIZE];
+        }
+        cola_norm /= DENOISE_HOP_SIZE as f32;
+
+        Self {
+            alpha,
+            beta,
+            window,
+            cola_norm: cola_norm.max(1e-6),
+            r2c,
+            c2r,
+            noise_mag: vec![0.0; num_bins],
+            noise_initialized: false,
+            energy_floor: 0.0,
+            floor_initialized: false,
+            input_carry: Vec::new(),
+            output_overlap: vec![0.0; DENOISE_FFT_SIZE],
+        }
+    }
+
+    /// Runs spectral subtraction on one `DENOISE_FFT_SIZE`-sample window and
+    /// overlap-adds the result, returning the `DENOISE_HOP_SIZE` samples of
+    /// output that are now final (won't receive further overlap).
+    fn process_frame(&mut self, frame: &[f32]) -> Vec<f32> {
+        let mut windowed: Vec<f32> = frame.iter().zip(&self.window).map(|(s, w)| s * w).collect();
+
+        let mut spectrum = self.r2c.make_output_vec();
+        let mut scratch = self.r2c.make_scratch_vec();
+        let _ = self.r2c.process_with_scratch(&mut windowed, &mut spectrum, &mut scratch);
+
+        // A quiet frame (relative to the slowly-adapting floor) is assumed
+        // to be noise-only and updates the noise-magnitude estimate;
+        // louder frames (presumably speech) leave it alone.
+        let frame_energy = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len().max(1) as f32).sqrt();
+        if !self.floor_initialized {
+            self.energy_floor = frame_energy;
+            self.floor_initialized = true;
+        } else {
+            self.energy_floor = (self.energy_floor * 1.02).min(0.95 * self.energy_floor + 0.05 * frame_energy);
+        }
+        let is_noise_frame = frame_energy <= self.energy_floor * 1.5;
+
+        for (bin, c) in spectrum.iter().enumerate() {
+            let mag = c.norm();
+            if !self.noise_initialized {
+                self.noise_mag[bin] = mag;
+            } else if is_noise_frame {
+                self.noise_mag[bin] = 0.95 * self.noise_mag[bin] + 0.05 * mag;
+            }
+        }
+        self.noise_initialized = true;
+
+        for (bin, c) in spectrum.iter_mut().enumerate() {
+            let mag = c.norm();
+            if mag <= 1e-12 {
+                continue;
+            }
+            let subtracted = (mag - self.alpha * self.noise_mag[bin]).max(self.beta * mag);
+            *c = *c / mag * subtracted;
+        }
+
+        let mut synth = self.c2r.make_output_vec();
+        let mut scratch = self.c2r.make_scratch_vec();
+        let _ = self.c2r.process_with_scratch(&mut spectrum, &mut synth, &mut scratch);
+
+        // realfft's inverse transform is unnormalized (scales by
+        // `DENOISE_FFT_SIZE`); fold that, the synthesis window, and the COLA
+        // normalization into one pass over the frame.
+        let ifft_norm = 1.0 / (DENOISE_FFT_SIZE as f32 * self.cola_norm);
+        for (i, s) in synth.iter().enumerate() {
+            self.output_overlap[i] += s * self.window[i] * ifft_norm;
+        }
+
+        let out: Vec<f32> = self.output_overlap[..DENOISE_HOP_SIZE].to_vec();
+        self.output_overlap.drain(0..DENOISE_HOP_SIZE);
+        self.output_overlap.extend(std::iter::repeat(0.0).take(DENOISE_HOP_SIZE));
+        out
+    }
+
+    /// Streaming entry point: buffers `input` until a full analysis window
+    /// is available, advancing by `DENOISE_HOP_SIZE` (50% overlap) each
+    /// frame, mirroring `SourceResampler::process`'s chunk-at-a-time shape.
+    fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        self.input_carry.extend_from_slice(input);
+
+        let mut out = Vec::new();
+        while self.input_carry.len() >= DENOISE_FFT_SIZE {
+            let frame: Vec<f32> = self.input_carry[..DENOISE_FFT_SIZE].to_vec();
+            out.extend(self.process_frame(&frame));
+            self.input_carry.drain(0..DENOISE_HOP_SIZE);
+        }
+        out
+    }
+}
+
+/// Soft-limits a mixed sample so several loud, in-phase sources summing past
+/// +-1.0 compresses smoothly into range instead of hard-clipping into
+/// audible distortion: an unscaled cubic `x - x^3/3` curve inside the
+/// `[-1, 1]` input range (output `[-2/3, 2/3]`), and a hard clamp at `+-2/3`
+/// beyond it where the cubic would start turning back downward.
+fn soft_clip(x: f32) -> f32 {
+    const LIMIT: f32 = 1.0;
+    if x.abs() <= LIMIT {
+        x - (x * x * x) / 3.0
+    } else {
+        (2.0 / 3.0) * x.signum()
+    }
+}
+
+/// Shared RIFF/WAVE writer for `export_source_wav`/`export_mixed_wav` -
+/// the same 16-bit PCM format `FileSystemManager::save_recording_wav`
+/// writes, so exported files are playable and re-transcribable the same
+/// way regardless of which manager produced them.
+fn write_wav(
+    path: &str,
+    samples: &[f32],
+    sample_rate: u32,
+    channels: u16,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let spec = hound::WavSpec {
+        channels,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    for sample in samples {
+        let pcm = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        writer.write_sample(pcm)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
+
+/// Deinterleaves `samples` by `source_channels` and lays each frame out to
+/// `target_channels`, modeled on cubeb-coreaudio's `mixer.rs` layout
+/// conversion: mono upmixes by duplicating the sample across every output
+/// channel, and multi-channel downmixes to mono by averaging. A frame
+/// that's neither a clean upmix nor downmix (e.g. 4ch -> 2ch) is averaged to
+/// mono first, then upmixed - the simplest well-defined conversion without a
+/// full channel map.
+fn mix_to_channel_layout(samples: &[f32], source_channels: usize, target_channels: usize) -> Vec<f32> {
+    let source_channels = source_channels.max(1);
+    let target_channels = target_channels.max(1);
+
+    if source_channels == target_channels {
+        return samples.to_vec();
+    }
+
+    let mut out = Vec::with_capacity((samples.len() / source_channels) * target_channels);
+    for frame in samples.chunks_exact(source_channels) {
+        if source_channels == 1 {
+            let s = frame[0];
+            out.extend(std::iter::repeat(s).take(target_channels));
+        } else {
+            let avg = frame.iter().sum::<f32>() / source_channels as f32;
+            if target_channels == 1 {
+                out.push(avg);
+            } else {
+                out.extend(std::iter::repeat(avg).take(target_channels));
+            }
+        }
+    }
+
+    out
+}
+
+/// Deterministic mono generators for `MultiSourceAudioCapture::add_synthetic_source` -
+/// enough to drive mixing/resampling/VAD through their real code paths in a
+/// test without a physical device, since only `discover_sources` and the
+/// `start_*_capture` methods actually need hardware.
+#[derive(Debug, Clone, Copy)]
+pub enum SyntheticSignal {
+    /// A pure sine tone at `frequency` Hz, peak amplitude `amplitude`.
+    Sine { frequency: f32, amplitude: f32 },
+    /// Uniform white noise in `[-amplitude, amplitude]`, generated from a
+    /// fixed-seed xorshift32 PRNG so runs are reproducible without pulling
+    /// in a `rand` dependency just for tests.
+    WhiteNoise { amplitude: f32 },
+    /// All zeros - a VAD-gated source that should never register as speaking.
+    Silence,
+}
+
+impl SyntheticSignal {
+    /// Renders `num_samples` of this signal at `sample_rate`, mono.
+    fn render(&self, sample_rate: u32, num_samples: usize) -> Vec<f32> {
+        match *self {
+            SyntheticSignal::Sine { frequency, amplitude } => (0..num_samples)
+                .map(|i| {
+                    let t = i as f32 / sample_rate.max(1) as f32;
+                    amplitude * (2.0 * std::f32::consts::PI * frequency * t).sin()
+                })
+                .collect(),
+            SyntheticSignal::WhiteNoise { amplitude } => {
+                let mut state: u32 = 0x9E3779B9;
+                (0..num_samples)
+                    .map(|_| {
+                        // xorshift32
+                        state ^= state << 13;
+                        state ^= state >> 17;
+                        state ^= state << 5;
+                        let unit = (state as f32 / u32::MAX as f32) * 2.0 - 1.0;
+                        unit * amplitude
+                    })
+                    .collect()
+            }
+            SyntheticSignal::Silence => vec![0.0; num_samples],
         }
     }
 }
@@ -52,27 +800,238 @@ pub struct MultiSourceAudioCapture {
     host: cpal::Host,
     config: MultiAudioConfig,
     sources: Arc<Mutex<HashMap<String, AudioSource>>>,
-    audio_buffers: Arc<Mutex<HashMap<String, Vec<f32>>>>,
+    source_buffers: Arc<Mutex<HashMap<String, SourceBuffer>>>,
 
     is_recording: Arc<AtomicBool>,
+    /// Checked by `push_with_policy` on every cpal callback so a pause mutes
+    /// capture at its earliest point instead of just gating the chunker
+    /// actor further downstream - see `pause`/`resume`.
+    paused: Arc<AtomicBool>,
     active_streams: Arc<Mutex<Vec<String>>>,
+    /// Terminal `CaptureError` (as a display string) recorded by a source's
+    /// cpal error callback, keyed by source id. The callback runs on cpal's
+    /// own real-time thread, not the async Tokio runtime, so it can only
+    /// touch plain `std::sync::Mutex` state - `get_source_stats` reconciles
+    /// this into each `AudioSource`'s `is_active`/`last_error` fields.
+    source_faults: Arc<std::sync::Mutex<HashMap<String, String>>>,
+    /// The process tap + aggregate device backing `start_coreaudio_loopback`
+    /// on macOS 14.2+, held so `stop_recording` can tear both down. `None`
+    /// when not recording, or when the pre-14.2 virtual-device fallback was
+    /// used instead (nothing to tear down in that case).
+    #[cfg(target_os = "macos")]
+    coreaudio_tap: Arc<Mutex<Option<coreaudio_tap::TapHandles>>>,
+    /// Woken by `push_with_policy` whenever any source's cpal callback lands
+    /// new samples, so `data_notify()` lets a consumer await real data
+    /// arriving instead of polling on a wall-clock timer.
+    data_notify: Arc<tokio::sync::Notify>,
+    /// Source ids whose cpal stream has been opened (and therefore leaked,
+    /// per `push_with_policy`'s doc comment) at least once. `add_source`
+    /// refuses to re-add one of these after `remove_source` dropped it,
+    /// since doing so would open - and leak - another stream for the same
+    /// device; without this, toggling a source on/off repeatedly during one
+    /// recording would leak one more stream per cycle.
+    ever_started_sources: Arc<std::sync::Mutex<HashSet<String>>>,
 }
 
 impl MultiSourceAudioCapture {
     /// Create new multi-source audio capture
     pub fn new(config: MultiAudioConfig) -> Self {
         let host = cpal::default_host();
-        
+
         Self {
             host,
             config,
             sources: Arc::new(Mutex::new(HashMap::new())),
-            audio_buffers: Arc::new(Mutex::new(HashMap::new())),
+            source_buffers: Arc::new(Mutex::new(HashMap::new())),
             is_recording: Arc::new(AtomicBool::new(false)),
+            paused: Arc::new(AtomicBool::new(false)),
             active_streams: Arc::new(Mutex::new(Vec::new())),
+            source_faults: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            #[cfg(target_os = "macos")]
+            coreaudio_tap: Arc::new(Mutex::new(None)),
+            data_notify: Arc::new(tokio::sync::Notify::new()),
+            ever_started_sources: Arc::new(std::sync::Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Clone of the notify woken whenever new audio lands in any source's
+    /// ring buffer - callers `notified().await` this instead of sleeping on
+    /// a fixed interval before pulling from `get_mixed_audio`/`get_source_audio`.
+    pub fn data_notify(&self) -> Arc<tokio::sync::Notify> {
+        self.data_notify.clone()
+    }
+
+    /// Builds a cpal stream-error callback for `source_id`: logs the error
+    /// (as before) and classifies + records it in `source_faults` so
+    /// `get_source_stats` reflects a source that died mid-recording instead
+    /// of silently going stale.
+    fn stream_error_handler(&self, source_id: &str) -> impl Fn(cpal::StreamError) + Send + 'static {
+        let source_faults = self.source_faults.clone();
+        let source_id = source_id.to_string();
+        move |err: cpal::StreamError| {
+            let fault = classify_stream_fault(&err);
+            eprintln!("❌ Audio stream error on {}: {}", source_id, fault);
+            if let Ok(mut faults) = source_faults.lock() {
+                faults.insert(source_id.clone(), fault.to_string());
+            }
         }
     }
 
+    pub fn is_recording(&self) -> bool {
+        self.is_recording.load(Ordering::Relaxed)
+    }
+
+    /// Mutes every source's cpal callback at `push_with_policy` without
+    /// tearing down any stream, so `resume` can pick back up instantly.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Un-mutes capture paused via `pause`.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Allocate a fresh ring buffer for `source_id` and register its consumer
+    /// half so `mix_audio_sources`/`get_source_audio` can read it later,
+    /// returning the producer half (plus its shared drop counter) for the
+    /// cpal callback to own.
+    async fn register_source_buffer(
+        &self,
+        source_id: &str,
+    ) -> (
+        Arc<std::sync::Mutex<HeapProd<f32>>>,
+        Arc<std::sync::Mutex<HeapCons<f32>>>,
+        Arc<AtomicU64>,
+        Arc<AtomicBool>,
+        Arc<AtomicU64>,
+        Arc<AtomicU32>,
+        Arc<AtomicU32>,
+    ) {
+        let capacity = (self.config.ring_buffer_seconds * self.config.sample_rate as f32).max(1.0) as usize;
+        let rb = HeapRb::<f32>::new(capacity);
+        let (producer, consumer) = rb.split();
+        let producer = Arc::new(std::sync::Mutex::new(producer));
+        let consumer = Arc::new(std::sync::Mutex::new(consumer));
+        let dropped_samples = Arc::new(AtomicU64::new(0));
+        let vad_active = Arc::new(AtomicBool::new(false));
+        let speech_samples = Arc::new(AtomicU64::new(0));
+        let level_rms_bits = Arc::new(AtomicU32::new(0));
+        let level_peak_bits = Arc::new(AtomicU32::new(0));
+
+        let mut buffers = self.source_buffers.lock().await;
+        buffers.insert(
+            source_id.to_string(),
+            SourceBuffer {
+                producer: producer.clone(),
+                consumer: consumer.clone(),
+                dropped_samples: dropped_samples.clone(),
+                vad_active: vad_active.clone(),
+                speech_samples: speech_samples.clone(),
+                level_rms_bits: level_rms_bits.clone(),
+                level_peak_bits: level_peak_bits.clone(),
+            },
+        );
+
+        (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits)
+    }
+
+    /// Clone out the producer/consumer/drop-counter handles for an
+    /// already-registered source, for a cpal callback to capture by `move`.
+    async fn source_push_handles(
+        &self,
+        source_id: &str,
+    ) -> Result<(
+        Arc<std::sync::Mutex<HeapProd<f32>>>,
+        Arc<std::sync::Mutex<HeapCons<f32>>>,
+        Arc<AtomicU64>,
+        Arc<AtomicBool>,
+        Arc<AtomicU64>,
+        Arc<AtomicU32>,
+        Arc<AtomicU32>,
+    )> {
+        let buffers = self.source_buffers.lock().await;
+        let buf = buffers
+            .get(source_id)
+            .ok_or_else(|| anyhow!("No ring buffer registered for source {}", source_id))?;
+        Ok((
+            buf.producer.clone(),
+            buf.consumer.clone(),
+            buf.dropped_samples.clone(),
+            buf.vad_active.clone(),
+            buf.speech_samples.clone(),
+            buf.level_rms_bits.clone(),
+            buf.level_peak_bits.clone(),
+        ))
+    }
+
+    /// Current RMS/peak level for `source_id`, as last computed by
+    /// `push_with_policy` - `(0.0, 0.0)` if the source hasn't pushed
+    /// anything yet or doesn't exist.
+    async fn get_source_level(&self, source_id: &str) -> (f32, f32) {
+        let buffers = self.source_buffers.lock().await;
+        match buffers.get(source_id) {
+            Some(buf) => (
+                f32::from_bits(buf.level_rms_bits.load(Ordering::Relaxed)),
+                f32::from_bits(buf.level_peak_bits.load(Ordering::Relaxed)),
+            ),
+            None => (0.0, 0.0),
+        }
+    }
+
+    /// `(source_id, kind, rms, peak)` for every currently active source -
+    /// feeds the actor's ~100ms metering tick (`audio:levels`) without
+    /// draining any source's ring buffer.
+    pub async fn get_active_source_levels(&self) -> Vec<(String, &'static str, f32, f32)> {
+        let sources = self.sources.lock().await;
+        let active_streams = self.active_streams.lock().await;
+        let mut levels = Vec::with_capacity(active_streams.len());
+        for source_id in active_streams.iter() {
+            let kind = sources
+                .get(source_id)
+                .map(|s| match s.device_type {
+                    AudioSourceType::Microphone => "mic",
+                    AudioSourceType::SystemAudio => "system",
+                    AudioSourceType::LineIn => "line_in",
+                    AudioSourceType::Virtual => "virtual",
+                })
+                .unwrap_or("unknown");
+            let (rms, peak) = self.get_source_level(source_id).await;
+            levels.push((source_id.clone(), kind, rms, peak));
+        }
+        levels
+    }
+
+    /// Per-source audio statistics, including samples the drop-oldest
+    /// overrun policy has evicted from each source's ring buffer since it
+    /// started recording.
+    pub async fn get_source_stats(&self) -> Vec<AudioSource> {
+        let sources = self.sources.lock().await;
+        let buffers = self.source_buffers.lock().await;
+        let faults = self.source_faults.lock().ok();
+
+        sources
+            .values()
+            .map(|source| {
+                let dropped_samples = buffers
+                    .get(&source.id)
+                    .map(|buf| buf.dropped_samples.load(Ordering::Relaxed))
+                    .unwrap_or(0);
+                let fault = faults.as_ref().and_then(|f| f.get(&source.id)).cloned();
+                AudioSource {
+                    dropped_samples,
+                    is_active: if fault.is_some() { false } else { source.is_active },
+                    last_error: fault.or_else(|| source.last_error.clone()),
+                    ..source.clone()
+                }
+            })
+            .collect()
+    }
+
     /// Discover all available audio sources
     pub async fn discover_sources(&self) -> Result<Vec<AudioSource>> {
         let mut sources = Vec::new();
@@ -94,6 +1053,75 @@ impl MultiSourceAudioCapture {
         Ok(sources)
     }
 
+    /// Registers `source_id` as an [`AudioSourceType::Virtual`] source and
+    /// pushes `duration_secs` of `generator`'s output straight into its ring
+    /// buffer, running it through the same resample -> VAD -> push pipeline
+    /// `start_*_capture` builds around a real cpal stream - lets tests
+    /// exercise `mix_audio_sources`/`get_status` deterministically without a
+    /// physical device, the way `test_source_discovery` can't.
+    pub async fn add_synthetic_source(
+        &self,
+        source_id: &str,
+        generator: SyntheticSignal,
+        source_sample_rate: u32,
+        duration_secs: f32,
+    ) -> Result<(), CaptureError> {
+        let source = AudioSource {
+            id: source_id.to_string(),
+            name: format!("Synthetic: {}", source_id),
+            device_type: AudioSourceType::Virtual,
+            channels: 1,
+            sample_rate: source_sample_rate,
+            is_active: true,
+            dropped_samples: 0,
+            gain: 1.0,
+            muted: false,
+            solo: false,
+            last_error: None,
+        };
+
+        {
+            let mut sources = self.sources.lock().await;
+            sources.insert(source_id.to_string(), source);
+        }
+        {
+            let mut active_streams = self.active_streams.lock().await;
+            if !active_streams.iter().any(|id| id == source_id) {
+                active_streams.push(source_id.to_string());
+            }
+        }
+
+        let (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits) =
+            self.register_source_buffer(source_id).await;
+
+        let num_samples = (source_sample_rate as f32 * duration_secs.max(0.0)) as usize;
+        let raw = generator.render(source_sample_rate, num_samples);
+
+        let mut resampler = SourceResampler::new(source_sample_rate, self.config.sample_rate);
+        let mut resampled = resampler.process(&raw);
+
+        if self.config.denoise_enabled {
+            let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+            resampled = denoiser.process(&resampled);
+        }
+
+        let mut vad = SourceVad::new(
+            self.config.sample_rate,
+            self.config.vad_threshold,
+            self.config.vad_hangover_ms,
+            vad_active,
+            speech_samples,
+        );
+        if self.config.vad_enabled {
+            vad.process(&mut resampled);
+        } else {
+            vad.mark_active();
+        }
+
+        push_with_policy(self.config.overrun_policy, &producer, &consumer, &dropped_samples, &self.data_notify, &self.paused, &level_rms_bits, &level_peak_bits, &resampled);
+        Ok(())
+    }
+
     /// Get system audio devices
     async fn get_system_audio_devices(&self) -> Result<Vec<AudioSource>> {
         let mut devices = Vec::new();
@@ -110,7 +1138,11 @@ impl MultiSourceAudioCapture {
                         channels: 2,
                         sample_rate: 44100,
                         is_active: false,
-                    });
+                        dropped_samples: 0,
+                        gain: 1.0,
+                        muted: false,
+                        solo: false,
+                    last_error: None,});
                 }
             }
         }
@@ -127,7 +1159,11 @@ impl MultiSourceAudioCapture {
                         channels: 2,
                         sample_rate: 44100,
                         is_active: false,
-                    });
+                        dropped_samples: 0,
+                        gain: 1.0,
+                        muted: false,
+                        solo: false,
+                    last_error: None,});
                 }
             }
         }
@@ -144,7 +1180,11 @@ impl MultiSourceAudioCapture {
                         channels: 2,
                         sample_rate: 44100,
                         is_active: false,
-                    });
+                        dropped_samples: 0,
+                        gain: 1.0,
+                        muted: false,
+                        solo: false,
+                    last_error: None,});
                 }
             }
         }
@@ -167,7 +1207,11 @@ impl MultiSourceAudioCapture {
                     channels: 1, // Most mics are mono
                     sample_rate: 44100,
                     is_active: false,
-                });
+                    dropped_samples: 0,
+                    gain: 1.0,
+                    muted: false,
+                    solo: false,
+                last_error: None,});
             }
         }
         }
@@ -176,26 +1220,33 @@ impl MultiSourceAudioCapture {
     }
 
     /// Start recording from multiple sources
-    pub async fn start_multi_recording(&self, source_ids: Vec<String>) -> Result<()> {
+    pub async fn start_multi_recording(&self, source_ids: Vec<String>) -> Result<(), CaptureError> {
         if self.is_recording.load(Ordering::Relaxed) {
-            return Err(anyhow!("Already recording"));
+            return Err(CaptureError::AlreadyRecording);
         }
 
         println!("🎙️ Starting multi-source recording with {} sources", source_ids.len());
 
-        let sources = self.sources.lock().await;
+        let mut sources = self.sources.lock().await;
         let mut active_streams = self.active_streams.lock().await;
 
         for source_id in source_ids {
-            if let Some(source) = sources.get(&source_id) {
-                match self.start_source_recording(source).await {
-                    Ok(_) => {
-                        active_streams.push(source_id.clone());
-                        println!("✅ Started recording: {}", source.name);
+            let Some(source) = sources.get(&source_id).cloned() else { continue };
+            match self.start_source_recording(&source).await {
+                Ok(_) => {
+                    active_streams.push(source_id.clone());
+                    self.ever_started_sources.lock().unwrap().insert(source_id.clone());
+                    if let Ok(mut faults) = self.source_faults.lock() {
+                        faults.remove(&source_id);
                     }
-                    Err(e) => {
-                        println!("❌ Failed to start {}: {}", source.name, e);
+                    if let Some(entry) = sources.get_mut(&source_id) {
+                        entry.is_active = true;
+                        entry.last_error = None;
                     }
+                    println!("✅ Started recording: {}", source.name);
+                }
+                Err(e) => {
+                    println!("❌ Failed to start {}: {}", source.name, e);
                 }
             }
         }
@@ -205,26 +1256,76 @@ impl MultiSourceAudioCapture {
             println!("🔴 Multi-source recording active with {} sources", active_streams.len());
             Ok(())
         } else {
-            Err(anyhow!("No sources started successfully"))
+            Err(CaptureError::NoSourcesStarted)
+        }
+    }
+
+    /// Starts capturing an additional source mid-recording without
+    /// disturbing already-active ones, so a session's source set can grow
+    /// live (see `AudioControlMessage::SetActiveSources`) instead of only
+    /// being fixable at `start_multi_recording` time. No-op if `source_id`
+    /// is already active; errors with `AlreadyStartedOnce` if `source_id`
+    /// was started and then `remove_source`'d earlier this session - its
+    /// stream is still running (leaked, per `push_with_policy`'s doc
+    /// comment) with nowhere for a second one to go, so re-adding it would
+    /// leak another stream per toggle cycle instead of reusing the first.
+    pub async fn add_source(&self, source_id: &str) -> Result<(), CaptureError> {
+        if self.active_streams.lock().await.iter().any(|id| id == source_id) {
+            return Ok(());
+        }
+        if !self.ever_started_sources.lock().unwrap().insert(source_id.to_string()) {
+            return Err(CaptureError::AlreadyStartedOnce(source_id.to_string()));
+        }
+        let source = self
+            .sources
+            .lock()
+            .await
+            .get(source_id)
+            .cloned()
+            .ok_or_else(|| CaptureError::DeviceNotFound(format!("unknown source {}", source_id)))?;
+
+        self.start_source_recording(&source).await?;
+        self.active_streams.lock().await.push(source_id.to_string());
+        if let Ok(mut faults) = self.source_faults.lock() {
+            faults.remove(source_id);
+        }
+        if let Some(entry) = self.sources.lock().await.get_mut(source_id) {
+            entry.is_active = true;
+            entry.last_error = None;
+        }
+        self.is_recording.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Drops `source_id` out of the active mix. Its cpal stream is already
+    /// leaked (see `push_with_policy`'s pause doc comment) and keeps
+    /// running, so this only stops `mix_audio_sources`/`get_source_audio`
+    /// from reading it further - the same "can't un-play a forgotten
+    /// stream" constraint `pause` works around, just scoped to one source
+    /// instead of all of them. `add_source` refuses to re-add `source_id`
+    /// after this, since its stream is still leaked and running.
+    pub async fn remove_source(&self, source_id: &str) {
+        self.active_streams.lock().await.retain(|id| id != source_id);
+        if let Some(entry) = self.sources.lock().await.get_mut(source_id) {
+            entry.is_active = false;
         }
     }
 
     /// Start recording from a specific source
-    async fn start_source_recording(&self, source: &AudioSource) -> Result<()> {
+    async fn start_source_recording(&self, source: &AudioSource) -> Result<(), CaptureError> {
         match source.device_type {
             AudioSourceType::SystemAudio => self.start_system_audio_capture(source).await,
             AudioSourceType::Microphone => self.start_microphone_capture(source).await,
-            _ => Err(anyhow!("Unsupported source type: {:?}", source.device_type)),
+            _ => Err(CaptureError::UnsupportedFormat(format!("source type {:?}", source.device_type))),
         }
     }
 
     /// Start system audio capture
-    async fn start_system_audio_capture(&self, source: &AudioSource) -> Result<()> {
+    async fn start_system_audio_capture(&self, source: &AudioSource) -> Result<(), CaptureError> {
         println!("🔊 Starting system audio capture: {}", source.name);
 
-        // Initialize buffer for this source
-        let mut buffers = self.audio_buffers.lock().await;
-        buffers.insert(source.id.clone(), Vec::new());
+        // Initialize the ring buffer for this source
+        self.register_source_buffer(&source.id).await;
 
         // Platform-specific system audio capture
         #[cfg(target_os = "windows")]
@@ -244,25 +1345,25 @@ impl MultiSourceAudioCapture {
 
         #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
         {
-            Err(anyhow!("System audio capture not supported on this platform"))
+            Err(CaptureError::UnsupportedFormat("system audio capture not supported on this platform".to_string()))
         }
     }
 
     /// Start microphone capture
-    async fn start_microphone_capture(&self, source: &AudioSource) -> Result<()> {
+    async fn start_microphone_capture(&self, source: &AudioSource) -> Result<(), CaptureError> {
         println!("🎤 Starting microphone capture: {}", source.name);
 
         // Find the microphone device
         let devices: Vec<_> = match self.host.input_devices() {
             Ok(devices) => devices.collect(),
-            Err(e) => return Err(anyhow!("Failed to get input devices: {}", e)),
+            Err(e) => return Err(CaptureError::DeviceNotFound(format!("failed to get input devices: {}", e))),
         };
         let device_index = source.id.strip_prefix("mic_")
             .and_then(|s| s.parse::<usize>().ok())
             .unwrap_or(0);
 
         let device = devices.get(device_index)
-            .ok_or_else(|| anyhow!("Microphone device not found"))?;
+            .ok_or_else(|| CaptureError::DeviceNotFound("microphone device not found".to_string()))?;
 
         // Prefer the device's default input config; otherwise pick a supported range (max rate)
         let supported = match device.default_input_config() {
@@ -270,60 +1371,101 @@ impl MultiSourceAudioCapture {
             Err(_) => {
                 let mut iter = device
                     .supported_input_configs()
-                    .map_err(|e| anyhow!("Failed to query supported input configs: {}", e))?;
+                    .map_err(|e| CaptureError::DeviceNotFound(format!("failed to query supported input configs: {}", e)))?;
                 let range = iter
                     .next()
-                    .ok_or_else(|| anyhow!("No supported input configs"))?;
+                    .ok_or_else(|| CaptureError::UnsupportedFormat("no supported input configs".to_string()))?;
                 range.with_max_sample_rate()
             }
         };
 
         let config: cpal::StreamConfig = supported.clone().into();
 
-        // Initialize buffer
-        let mut buffers = self.audio_buffers.lock().await;
-        buffers.insert(source.id.clone(), Vec::new());
-        drop(buffers);
+        // The device's true negotiated rate, not `discover_sources`' 44100
+        // placeholder - this is what actually needs resampling to the mix target.
+        let device_sample_rate = supported.sample_rate().0;
+        {
+            let mut sources = self.sources.lock().await;
+            if let Some(existing) = sources.get_mut(&source.id) {
+                existing.sample_rate = device_sample_rate;
+            }
+        }
 
-        let audio_buffers = Arc::clone(&self.audio_buffers);
-        let source_id = source.id.clone();
+        // Initialize the ring buffer for this source
+        let (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits) = self.register_source_buffer(&source.id).await;
+        let policy = self.config.overrun_policy;
+        let notify = self.data_notify.clone();
+        let paused = self.paused.clone();
+        let level_rms_bits = level_rms_bits.clone();
+        let level_peak_bits = level_peak_bits.clone();
+        let mut resampler = SourceResampler::new(device_sample_rate, self.config.sample_rate);
+        let vad_enabled = self.config.vad_enabled;
+        let denoise_enabled = self.config.denoise_enabled;
+        let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+        let mut vad = SourceVad::new(
+            self.config.sample_rate,
+            self.config.vad_threshold,
+            self.config.vad_hangover_ms,
+            vad_active,
+            speech_samples,
+        );
 
         use cpal::SampleFormat;
         let stream = match supported.sample_format() {
             SampleFormat::F32 => device.build_input_stream(
                 &config,
                 move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                    let mut buffers = block_on(audio_buffers.lock());
-                    if let Some(buffer) = buffers.get_mut(&source_id) {
-                        buffer.extend_from_slice(data);
+                    let mut resampled = resampler.process(data);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
                     }
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
+                    }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                 },
-                |err| eprintln!("❌ Microphone stream error: {}", err),
+                self.stream_error_handler(&source.id),
                 None,
             )?,
             SampleFormat::I16 => device.build_input_stream(
                 &config,
                 move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                    let mut buffers = block_on(audio_buffers.lock());
-                    if let Some(buffer) = buffers.get_mut(&source_id) {
-                        for &s in data { buffer.push(s as f32 / i16::MAX as f32); }
+                    let samples: Vec<f32> = data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                    let mut resampled = resampler.process(&samples);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
+                    }
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
                     }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                 },
-                |err| eprintln!("❌ Microphone stream error: {}", err),
+                self.stream_error_handler(&source.id),
                 None,
             )?,
             SampleFormat::U16 => device.build_input_stream(
                 &config,
                 move |data: &[u16], _: &cpal::InputCallbackInfo| {
-                    let mut buffers = block_on(audio_buffers.lock());
-                    if let Some(buffer) = buffers.get_mut(&source_id) {
-                        for &s in data { buffer.push(s as f32 / u16::MAX as f32 * 2.0 - 1.0); }
+                    let samples: Vec<f32> = data.iter().map(|&s| s as f32 / u16::MAX as f32 * 2.0 - 1.0).collect();
+                    let mut resampled = resampler.process(&samples);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
+                    }
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
                     }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                 },
-                |err| eprintln!("❌ Microphone stream error: {}", err),
+                self.stream_error_handler(&source.id),
                 None,
             )?,
-            _ => return Err(anyhow!("Unsupported microphone sample format")),
+            _ => return Err(CaptureError::UnsupportedFormat("unsupported microphone sample format".to_string())),
         };
 
         stream.play()?;
@@ -333,12 +1475,14 @@ impl MultiSourceAudioCapture {
 
     /// Platform-specific system audio implementations
     #[cfg(target_os = "windows")]
-    async fn start_wasapi_loopback(&self, source_id: &str) -> Result<()> {
+    async fn start_wasapi_loopback(&self, source_id: &str) -> Result<(), CaptureError> {
         // Windows WASAPI loopback capture
         println!("🪟 Using WASAPI loopback for system audio");
         
         let device = self.host.default_output_device()
-            .ok_or_else(|| anyhow!("No output device found"))?;
+            .ok_or_else(|| CaptureError::DeviceNotFound("no output device found".to_string()))?;
+
+        let (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits) = self.source_push_handles(source_id).await?;
 
         // Try to get an input config for loopback; if none, try output config; else fallback to Stereo Mix-like inputs
         let supported_input_opt = match device.default_input_config() {
@@ -348,44 +1492,83 @@ impl MultiSourceAudioCapture {
 
         let use_device_stream = if let Some(supported) = supported_input_opt {
             let config: cpal::StreamConfig = supported.clone().into();
-            let audio_buffers = Arc::clone(&self.audio_buffers);
-            let source_id = source_id.to_string();
+            let producer = producer.clone();
+            let consumer = consumer.clone();
+            let dropped_samples = dropped_samples.clone();
+            let vad_active = vad_active.clone();
+            let speech_samples = speech_samples.clone();
+            let policy = self.config.overrun_policy;
+            let notify = self.data_notify.clone();
+            let paused = self.paused.clone();
+            let level_rms_bits = level_rms_bits.clone();
+            let level_peak_bits = level_peak_bits.clone();
+            let mut resampler = SourceResampler::new(supported.sample_rate().0, self.config.sample_rate);
+            let vad_enabled = self.config.vad_enabled;
+            let denoise_enabled = self.config.denoise_enabled;
+            let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+            let mut vad = SourceVad::new(
+                self.config.sample_rate,
+                self.config.vad_threshold,
+                self.config.vad_hangover_ms,
+                vad_active,
+                speech_samples,
+            );
             use cpal::SampleFormat;
             let stream = match supported.sample_format() {
                 SampleFormat::F32 => device.build_input_stream(
                     &config,
                     move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                        let mut buffers = block_on(audio_buffers.lock());
-                        if let Some(buffer) = buffers.get_mut(&source_id) {
-                            buffer.extend_from_slice(data);
+                        let mut resampled = resampler.process(data);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
+                        }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
                         }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                     },
-                    |err| eprintln!("❌ WASAPI stream error: {}", err),
+                    self.stream_error_handler(source_id),
                     None,
                 )?,
                 SampleFormat::I16 => device.build_input_stream(
                     &config,
                     move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                        let mut buffers = block_on(audio_buffers.lock());
-                        if let Some(buffer) = buffers.get_mut(&source_id) {
-                            for &s in data { buffer.push(s as f32 / i16::MAX as f32); }
+                        let samples: Vec<f32> = data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                        let mut resampled = resampler.process(&samples);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
                         }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
+                        }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                     },
-                    |err| eprintln!("❌ WASAPI stream error: {}", err),
+                    self.stream_error_handler(source_id),
                     None,
                 )?,
                 SampleFormat::U16 => device.build_input_stream(
                     &config,
                     move |data: &[u16], _: &cpal::InputCallbackInfo| {
-                        let mut buffers = block_on(audio_buffers.lock());
-                        if let Some(buffer) = buffers.get_mut(&source_id) {
-                            for &s in data { buffer.push(s as f32 / u16::MAX as f32 * 2.0 - 1.0); }
+                        let samples: Vec<f32> = data.iter().map(|&s| s as f32 / u16::MAX as f32 * 2.0 - 1.0).collect();
+                        let mut resampled = resampler.process(&samples);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
+                        }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
                         }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                     },
-                    |err| eprintln!("❌ WASAPI stream error: {}", err),
+                    self.stream_error_handler(source_id),
                     None,
                 )?,
-                _ => return Err(anyhow!("Unsupported loopback sample format")),
+                _ => return Err(CaptureError::UnsupportedFormat("unsupported loopback sample format".to_string())),
             };
             stream.play()?;
             std::mem::forget(stream);
@@ -393,17 +1576,42 @@ impl MultiSourceAudioCapture {
         } else if let Ok(output_cfg) = device.default_output_config() {
             // Last-ditch: use output config to build input stream (some WASAPI loopback setups report only output configs)
             let config: cpal::StreamConfig = output_cfg.clone().into();
-            let audio_buffers = Arc::clone(&self.audio_buffers);
-            let source_id = source_id.to_string();
+            let producer = producer.clone();
+            let consumer = consumer.clone();
+            let dropped_samples = dropped_samples.clone();
+            let vad_active = vad_active.clone();
+            let speech_samples = speech_samples.clone();
+            let policy = self.config.overrun_policy;
+            let notify = self.data_notify.clone();
+            let paused = self.paused.clone();
+            let level_rms_bits = level_rms_bits.clone();
+            let level_peak_bits = level_peak_bits.clone();
+            let mut resampler = SourceResampler::new(output_cfg.sample_rate().0, self.config.sample_rate);
+            let vad_enabled = self.config.vad_enabled;
+            let denoise_enabled = self.config.denoise_enabled;
+            let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+            let mut vad = SourceVad::new(
+                self.config.sample_rate,
+                self.config.vad_threshold,
+                self.config.vad_hangover_ms,
+                vad_active,
+                speech_samples,
+            );
             let stream = device.build_input_stream(
                 &config,
                 move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                    let mut buffers = block_on(audio_buffers.lock());
-                    if let Some(buffer) = buffers.get_mut(&source_id) {
-                        buffer.extend_from_slice(data);
+                    let mut resampled = resampler.process(data);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
                     }
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
+                    }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                 },
-                |err| eprintln!("❌ WASAPI loopback (output-config) stream error: {}", err),
+                self.stream_error_handler(source_id),
                 None,
             );
             if let Ok(stream) = stream {
@@ -415,7 +1623,7 @@ impl MultiSourceAudioCapture {
 
         if !use_device_stream {
             // Fallback to Stereo Mix-like input devices
-            let candidates = ["stereo mix", "what u hear", "loopback", "output", "speaker"]; 
+            let candidates = ["stereo mix", "what u hear", "loopback", "output", "speaker"];
             let mut found: Option<cpal::Device> = None;
             if let Ok(inputs) = self.host.input_devices() {
                 for dev in inputs {
@@ -428,48 +1636,93 @@ impl MultiSourceAudioCapture {
                     }
                 }
             }
-            let mic_dev = found.ok_or_else(|| anyhow!("No supported input configs for loopback"))?;
+            let mic_dev = found.ok_or_else(|| CaptureError::DeviceNotFound("no supported input configs for loopback".to_string()))?;
             let supported = match mic_dev.default_input_config() {
                 Ok(cfg) => cfg,
                 Err(_) => {
-                    let mut it = mic_dev.supported_input_configs().map_err(|e| anyhow!("Failed to query fallback input configs: {}", e))?;
-                    let range = it.next().ok_or_else(|| anyhow!("No fallback input configs"))?;
+                    let mut it = mic_dev.supported_input_configs().map_err(|e| CaptureError::DeviceNotFound(format!("failed to query fallback input configs: {}", e)))?;
+                    let range = it.next().ok_or_else(|| CaptureError::UnsupportedFormat("no fallback input configs".to_string()))?;
                     range.with_max_sample_rate()
                 }
             };
             let config: cpal::StreamConfig = supported.clone().into();
-            let audio_buffers = Arc::clone(&self.audio_buffers);
-            let sid = source_id.to_string();
+            let producer = producer.clone();
+            let consumer = consumer.clone();
+            let dropped_samples = dropped_samples.clone();
+            let vad_active = vad_active.clone();
+            let speech_samples = speech_samples.clone();
+            let policy = self.config.overrun_policy;
+            let notify = self.data_notify.clone();
+            let paused = self.paused.clone();
+            let level_rms_bits = level_rms_bits.clone();
+            let level_peak_bits = level_peak_bits.clone();
+            let mut resampler = SourceResampler::new(supported.sample_rate().0, self.config.sample_rate);
+            let vad_enabled = self.config.vad_enabled;
+            let denoise_enabled = self.config.denoise_enabled;
+            let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+            let mut vad = SourceVad::new(
+                self.config.sample_rate,
+                self.config.vad_threshold,
+                self.config.vad_hangover_ms,
+                vad_active,
+                speech_samples,
+            );
             use cpal::SampleFormat;
             let stream = match supported.sample_format() {
                 SampleFormat::F32 => mic_dev.build_input_stream(
                     &config,
                     move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                        let mut buffers = block_on(audio_buffers.lock());
-                        if let Some(buffer) = buffers.get_mut(&sid) { buffer.extend_from_slice(data); }
+                        let mut resampled = resampler.process(data);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
+                        }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
+                        }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                     },
-                    |err| eprintln!("❌ Fallback stream error: {}", err),
+                    self.stream_error_handler(source_id),
                     None,
                 )?,
                 SampleFormat::I16 => mic_dev.build_input_stream(
                     &config,
                     move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                        let mut buffers = block_on(audio_buffers.lock());
-                        if let Some(buffer) = buffers.get_mut(&sid) { for &s in data { buffer.push(s as f32 / i16::MAX as f32); } }
+                        let samples: Vec<f32> = data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                        let mut resampled = resampler.process(&samples);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
+                        }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
+                        }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                     },
-                    |err| eprintln!("❌ Fallback stream error: {}", err),
+                    self.stream_error_handler(source_id),
                     None,
                 )?,
                 SampleFormat::U16 => mic_dev.build_input_stream(
                     &config,
                     move |data: &[u16], _: &cpal::InputCallbackInfo| {
-                        let mut buffers = block_on(audio_buffers.lock());
-                        if let Some(buffer) = buffers.get_mut(&sid) { for &s in data { buffer.push(s as f32 / u16::MAX as f32 * 2.0 - 1.0); } }
+                        let samples: Vec<f32> = data.iter().map(|&s| s as f32 / u16::MAX as f32 * 2.0 - 1.0).collect();
+                        let mut resampled = resampler.process(&samples);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
+                        }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
+                        }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
                     },
-                    |err| eprintln!("❌ Fallback stream error: {}", err),
+                    self.stream_error_handler(source_id),
                     None,
                 )?,
-                _ => return Err(anyhow!("Unsupported fallback sample format")),
+                _ => return Err(CaptureError::UnsupportedFormat("unsupported fallback sample format".to_string())),
             };
             stream.play()?;
             std::mem::forget(stream);
@@ -479,40 +1732,134 @@ impl MultiSourceAudioCapture {
     }
 
     #[cfg(target_os = "macos")]
-    async fn start_coreaudio_loopback(&self, source_id: &str) -> Result<()> {
-        // macOS CoreAudio aggregate device
+    async fn start_coreaudio_loopback(&self, source_id: &str) -> Result<(), CaptureError> {
         println!("🍎 Using CoreAudio for system audio");
-        
-        // For now, use similar approach to WASAPI
-        // Placeholder: CoreAudio aggregate device implementation
-        let device = self.host.default_output_device()
-            .ok_or_else(|| anyhow!("No output device found"))?;
 
-        let config = cpal::StreamConfig {
-            channels: 2,
-            sample_rate: cpal::SampleRate(44100),
-            buffer_size: cpal::BufferSize::Fixed(self.config.buffer_size as u32),
-        };
+        // Real loopback: a process tap (macOS 14.2+) describing system-wide
+        // output, wrapped together with the default output device into an
+        // aggregate device cpal can open as an input - see `coreaudio_tap`.
+        // Pre-14.2, fall back to a known virtual loopback device by name,
+        // mirroring the Windows Stereo-Mix fallback.
+        match coreaudio_tap::create_system_tap_aggregate("dgMeets System Audio Tap") {
+            Ok(handles) => {
+                let aggregate_uid = handles.aggregate_device_uid.clone();
+                *self.coreaudio_tap.lock().await = Some(handles);
+
+                let device = self.host.input_devices()
+                    .map_err(|e| CaptureError::DeviceNotFound(format!("failed to enumerate input devices: {}", e)))?
+                    .find(|d| d.name().map(|n| n == aggregate_uid).unwrap_or(false))
+                    .ok_or_else(|| CaptureError::DeviceNotFound("aggregate tap device not visible to cpal yet".to_string()))?;
+
+                let supported = device.default_input_config()
+                    .map_err(|e| CaptureError::UnsupportedFormat(format!("aggregate tap device has no input config: {}", e)))?;
+                let config: cpal::StreamConfig = supported.clone().into();
+
+                let (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits) = self.source_push_handles(source_id).await?;
+                let policy = self.config.overrun_policy;
+                let notify = self.data_notify.clone();
+                let paused = self.paused.clone();
+                let level_rms_bits = level_rms_bits.clone();
+                let level_peak_bits = level_peak_bits.clone();
+                let mut resampler = SourceResampler::new(supported.sample_rate().0, self.config.sample_rate);
+                let vad_enabled = self.config.vad_enabled;
+                let denoise_enabled = self.config.denoise_enabled;
+                let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+                let mut vad = SourceVad::new(
+                    self.config.sample_rate,
+                    self.config.vad_threshold,
+                    self.config.vad_hangover_ms,
+                    vad_active,
+                    speech_samples,
+                );
+
+                let stream = device.build_input_stream(
+                    &config,
+                    move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                        let mut resampled = resampler.process(data);
+                        if denoise_enabled {
+                            resampled = denoiser.process(&resampled);
+                        }
+                        if vad_enabled {
+                            vad.process(&mut resampled);
+                        } else {
+                            vad.mark_active();
+                        }
+                        push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
+                    },
+                    self.stream_error_handler(source_id),
+                    None,
+                )?;
 
-        let audio_buffers = Arc::clone(&self.audio_buffers);
-        let source_id = source_id.to_string();
+                stream.play()?;
+                std::mem::forget(stream);
+                Ok(())
+            }
+            Err(e) => {
+                println!("⚠️ Process tap unavailable ({}), falling back to a virtual loopback device", e);
+                self.start_coreaudio_virtual_device_fallback(source_id).await
+            }
+        }
+    }
+
+    /// Pre-14.2 (or tap-unsupported) fallback: look for a known virtual
+    /// loopback input device - e.g. BlackHole or Soundflower - the user has
+    /// installed, the same role Stereo Mix plays in the Windows fallback.
+    #[cfg(target_os = "macos")]
+    async fn start_coreaudio_virtual_device_fallback(&self, source_id: &str) -> Result<(), CaptureError> {
+        let candidates = ["blackhole", "loopback", "soundflower"];
+        let mut found: Option<cpal::Device> = None;
+        if let Ok(inputs) = self.host.input_devices() {
+            for dev in inputs {
+                if let Ok(name) = dev.name() {
+                    let lname = name.to_lowercase();
+                    if candidates.iter().any(|k| lname.contains(k)) {
+                        found = Some(dev);
+                        break;
+                    }
+                }
+            }
+        }
+        let device = found.ok_or_else(|| CaptureError::DeviceNotFound(
+            "no process tap support and no virtual loopback device (BlackHole/Soundflower) found".to_string()
+        ))?;
+
+        let supported = device.default_input_config()
+            .map_err(|e| CaptureError::UnsupportedFormat(format!("virtual loopback device has no input config: {}", e)))?;
+        let config: cpal::StreamConfig = supported.clone().into();
+
+        let (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits) = self.source_push_handles(source_id).await?;
+        let policy = self.config.overrun_policy;
+        let notify = self.data_notify.clone();
+        let paused = self.paused.clone();
+        let level_rms_bits = level_rms_bits.clone();
+        let level_peak_bits = level_peak_bits.clone();
+        let mut resampler = SourceResampler::new(supported.sample_rate().0, self.config.sample_rate);
+        let vad_enabled = self.config.vad_enabled;
+        let denoise_enabled = self.config.denoise_enabled;
+        let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+        let mut vad = SourceVad::new(
+            self.config.sample_rate,
+            self.config.vad_threshold,
+            self.config.vad_hangover_ms,
+            vad_active,
+            speech_samples,
+        );
 
         let stream = device.build_input_stream(
             &config,
             move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                let audio_buffers = Arc::clone(&audio_buffers);
-                let source_id = source_id.clone();
-
-                let data_copy = data.to_vec(); // Copy data to avoid lifetime issues
-                tokio::spawn(async move {
-                    if let Ok(mut buffers) = audio_buffers.try_lock() {
-                        if let Some(buffer) = buffers.get_mut(&source_id) {
-                            buffer.extend_from_slice(&data_copy);
-                        }
-                    }
-                });
+                let mut resampled = resampler.process(data);
+                if denoise_enabled {
+                    resampled = denoiser.process(&resampled);
+                }
+                if vad_enabled {
+                    vad.process(&mut resampled);
+                } else {
+                    vad.mark_active();
+                }
+                push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
             },
-            |err| eprintln!("❌ CoreAudio stream error: {}", err),
+            self.stream_error_handler(source_id),
             None,
         )?;
 
@@ -522,42 +1869,116 @@ impl MultiSourceAudioCapture {
     }
 
     #[cfg(target_os = "linux")]
-    async fn start_pulse_monitor(&self, source_id: &str) -> Result<()> {
-        // Linux PulseAudio monitor
+    async fn start_pulse_monitor(&self, source_id: &str) -> Result<(), CaptureError> {
         println!("🐧 Using PulseAudio monitor for system audio");
-        
-        // Placeholder: PulseAudio monitor source implementation
-        // For now, use default approach
-        let device = self.host.default_output_device()
-            .ok_or_else(|| anyhow!("No output device found"))?;
 
-        let config = cpal::StreamConfig {
-            channels: 2,
-            sample_rate: cpal::SampleRate(44100),
-            buffer_size: cpal::BufferSize::Fixed(self.config.buffer_size as u32),
+        // ALSA/PulseAudio never exposes the playback signal on the output
+        // device itself - a monitor *source* has to be enabled (PulseAudio
+        // does this by default; PipeWire's pipewire-pulse shim does too) and
+        // opened as an input, the same role "stereo mix" plays on Windows.
+        let candidates = [".monitor", "monitor"];
+        let mut found: Option<cpal::Device> = None;
+        if let Ok(inputs) = self.host.input_devices() {
+            for dev in inputs {
+                if let Ok(name) = dev.name() {
+                    let lname = name.to_lowercase();
+                    if candidates.iter().any(|k| lname.contains(k)) {
+                        found = Some(dev);
+                        break;
+                    }
+                }
+            }
+        }
+        let device = found.ok_or_else(|| CaptureError::DeviceNotFound(
+            "no PulseAudio/PipeWire monitor source found - enable the default sink's monitor (e.g. `pactl load-module module-loopback` is NOT needed; the monitor is usually on by default, check `pactl list sources short` for a `*.monitor` entry)".to_string()
+        ))?;
+
+        let supported = match device.default_input_config() {
+            Ok(cfg) => cfg,
+            Err(_) => {
+                let mut it = device.supported_input_configs()
+                    .map_err(|e| CaptureError::DeviceNotFound(format!("failed to query monitor source configs: {}", e)))?;
+                let range = it.next().ok_or_else(|| CaptureError::UnsupportedFormat("no supported configs for monitor source".to_string()))?;
+                range.with_max_sample_rate()
+            }
         };
+        let config: cpal::StreamConfig = supported.clone().into();
 
-        let audio_buffers = Arc::clone(&self.audio_buffers);
-        let source_id = source_id.to_string();
+        let (producer, consumer, dropped_samples, vad_active, speech_samples, level_rms_bits, level_peak_bits) = self.source_push_handles(source_id).await?;
+        let policy = self.config.overrun_policy;
+        let notify = self.data_notify.clone();
+        let paused = self.paused.clone();
+        let level_rms_bits = level_rms_bits.clone();
+        let level_peak_bits = level_peak_bits.clone();
+        let mut resampler = SourceResampler::new(supported.sample_rate().0, self.config.sample_rate);
+        let vad_enabled = self.config.vad_enabled;
+        let denoise_enabled = self.config.denoise_enabled;
+        let mut denoiser = SpectralDenoiser::new(self.config.denoise_alpha, self.config.denoise_beta);
+        let mut vad = SourceVad::new(
+            self.config.sample_rate,
+            self.config.vad_threshold,
+            self.config.vad_hangover_ms,
+            vad_active,
+            speech_samples,
+        );
 
-        let stream = device.build_input_stream(
-            &config,
-            move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                let audio_buffers = Arc::clone(&audio_buffers);
-                let source_id = source_id.clone();
-
-                let data_copy = data.to_vec(); // Copy data to avoid lifetime issues
-                tokio::spawn(async move {
-                    if let Ok(mut buffers) = audio_buffers.try_lock() {
-                        if let Some(buffer) = buffers.get_mut(&source_id) {
-                            buffer.extend_from_slice(&data_copy);
-                        }
+        use cpal::SampleFormat;
+        let stream = match supported.sample_format() {
+            SampleFormat::F32 => device.build_input_stream(
+                &config,
+                move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                    let mut resampled = resampler.process(data);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
                     }
-                });
-            },
-            |err| eprintln!("❌ PulseAudio stream error: {}", err),
-            None,
-        )?;
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
+                    }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
+                },
+                self.stream_error_handler(source_id),
+                None,
+            )?,
+            SampleFormat::I16 => device.build_input_stream(
+                &config,
+                move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                    let samples: Vec<f32> = data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                    let mut resampled = resampler.process(&samples);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
+                    }
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
+                    }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
+                },
+                self.stream_error_handler(source_id),
+                None,
+            )?,
+            SampleFormat::U16 => device.build_input_stream(
+                &config,
+                move |data: &[u16], _: &cpal::InputCallbackInfo| {
+                    let samples: Vec<f32> = data.iter().map(|&s| s as f32 / u16::MAX as f32 * 2.0 - 1.0).collect();
+                    let mut resampled = resampler.process(&samples);
+                    if denoise_enabled {
+                        resampled = denoiser.process(&resampled);
+                    }
+                    if vad_enabled {
+                        vad.process(&mut resampled);
+                    } else {
+                        vad.mark_active();
+                    }
+                    push_with_policy(policy, &producer, &consumer, &dropped_samples, &notify, &paused, &level_rms_bits, &level_peak_bits, &resampled);
+                },
+                self.stream_error_handler(source_id),
+                None,
+            )?,
+            _ => return Err(CaptureError::UnsupportedFormat("unsupported monitor source sample format".to_string())),
+        };
 
         stream.play()?;
         std::mem::forget(stream);
@@ -571,10 +1992,30 @@ impl MultiSourceAudioCapture {
         }
 
         self.is_recording.store(false, Ordering::Relaxed);
-        
+        self.paused.store(false, Ordering::Relaxed);
+
         let mut active_streams = self.active_streams.lock().await;
+        let mut sources = self.sources.lock().await;
+        for source_id in active_streams.iter() {
+            if let Some(source) = sources.get_mut(source_id) {
+                source.is_active = false;
+            }
+        }
         active_streams.clear();
-        
+        if let Ok(mut faults) = self.source_faults.lock() {
+            faults.clear();
+        }
+        // A fresh `start_multi_recording` after this is a new session, not a
+        // toggle within the one that just ended - sources it (re-)starts
+        // shouldn't be refused by the `add_source` "already started once"
+        // guard just because they were used before this stop.
+        self.ever_started_sources.lock().unwrap().clear();
+
+        #[cfg(target_os = "macos")]
+        if let Some(handles) = self.coreaudio_tap.lock().await.take() {
+            coreaudio_tap::destroy_system_tap_aggregate(handles);
+        }
+
         println!("⏹️ Multi-source recording stopped");
         Ok(())
     }
@@ -589,63 +2030,200 @@ impl MultiSourceAudioCapture {
         }
     }
 
-    /// Mix audio from all active sources
+    /// Mix audio from all active sources. Unlike the old `Vec`-backed
+    /// implementation, this actually drains the common prefix it mixes
+    /// (rather than just peeking), so buffers don't grow without bound -
+    /// mirroring the accumulate-then-mix-then-drain pattern `audio.rs` uses
+    /// for its own aggregate mixer. Sources report heterogeneous channel
+    /// counts (mono mic vs. stereo system loopback), so the common unit
+    /// across buffers is *frames*, not raw samples - each source is
+    /// deinterleaved and laid out to `MultiAudioConfig.channels` via
+    /// `mix_to_channel_layout` before being summed.
     async fn mix_audio_sources(&self, max_samples: Option<usize>) -> Vec<f32> {
-        let buffers = self.audio_buffers.lock().await;
+        let sources = self.sources.lock().await;
+        let mut buffers = self.source_buffers.lock().await;
         let active_streams = self.active_streams.lock().await;
 
         if active_streams.is_empty() {
             return Vec::new();
         }
 
-        // Find the minimum length across all buffers
-        let min_length = active_streams.iter()
-            .filter_map(|id| buffers.get(id))
-            .map(|buffer| buffer.len())
+        let target_channels = self.config.channels.max(1) as usize;
+
+        // Find the minimum number of whole frames available across all
+        // active sources' buffers.
+        let min_frames = active_streams.iter()
+            .filter_map(|id| {
+                let channels = sources.get(id)?.channels.max(1) as usize;
+                let buf = buffers.get(id)?;
+                let occupied = buf.consumer.lock().map(|c| c.occupied_len()).unwrap_or(0);
+                Some(occupied / channels)
+            })
             .min()
             .unwrap_or(0);
 
-        let samples_to_mix = max_samples.map(|max| max.min(min_length)).unwrap_or(min_length);
-        
-        if samples_to_mix == 0 {
+        let frames_to_mix = max_samples
+            .map(|max| (max / target_channels).min(min_frames))
+            .unwrap_or(min_frames);
+
+        if frames_to_mix == 0 {
             return Vec::new();
         }
 
-        let mut mixed = vec![0.0f32; samples_to_mix];
-        let num_sources = active_streams.len() as f32;
+        let mut mixed = vec![0.0f32; frames_to_mix * target_channels];
+
+        // When any active source is soloed, only soloed sources contribute
+        // to the mix - everyone else is still drained below (so their
+        // buffers don't grow unbounded while soloed), just not summed in.
+        let any_solo = active_streams.iter().any(|id| sources.get(id).is_some_and(|s| s.solo));
 
-        // Mix all sources
         for stream_id in active_streams.iter() {
-            if let Some(buffer) = buffers.get(stream_id) {
-                for (i, sample) in buffer.iter().take(samples_to_mix).enumerate() {
-                    mixed[i] += sample / num_sources; // Average mixing
-                }
+            let Some(source) = sources.get(stream_id) else { continue };
+            let Some(buf) = buffers.get_mut(stream_id) else { continue };
+            let Ok(mut consumer) = buf.consumer.lock() else { continue };
+
+            let source_channels = source.channels.max(1) as usize;
+            let raw: Vec<f32> = consumer.pop_iter().take(frames_to_mix * source_channels).collect();
+            let audible = !source.muted && (!any_solo || source.solo);
+            if !audible {
+                continue;
+            }
+            let laid_out = mix_to_channel_layout(&raw, source_channels, target_channels);
+
+            // Weighted sum, not an average - `gain` is the user-facing knob
+            // for balancing sources (e.g. keeping the mic dominant over a
+            // quiet system-audio stream), so dividing by source count would
+            // fight it. `soft_clip` below is what keeps several loud,
+            // in-phase sources from overflowing instead.
+            for (i, sample) in laid_out.iter().enumerate().take(mixed.len()) {
+                mixed[i] += sample * source.gain;
             }
         }
 
+        for sample in mixed.iter_mut() {
+            *sample = soft_clip(*sample);
+        }
+
         mixed
     }
 
-    /// Get separate audio data from specific source
+    /// Sets the gain multiplier applied to `source_id` in `mix_audio_sources`.
+    /// Takes effect on already-registered sources (discovered or currently
+    /// recording); unknown ids are a no-op error rather than silently doing
+    /// nothing, so callers can tell a typo'd id from a real update.
+    pub async fn set_source_gain(&self, source_id: &str, gain: f32) -> Result<(), CaptureError> {
+        let mut sources = self.sources.lock().await;
+        let source = sources.get_mut(source_id)
+            .ok_or_else(|| CaptureError::DeviceNotFound(format!("unknown source id: {}", source_id)))?;
+        source.gain = gain;
+        Ok(())
+    }
+
+    /// Mutes or unmutes `source_id` in `mix_audio_sources`. A muted source
+    /// is excluded from the mix regardless of `gain` or `solo`, but keeps
+    /// recording/draining normally so unmuting mid-session picks back up
+    /// with no gap.
+    pub async fn set_source_muted(&self, source_id: &str, muted: bool) -> Result<(), CaptureError> {
+        let mut sources = self.sources.lock().await;
+        let source = sources.get_mut(source_id)
+            .ok_or_else(|| CaptureError::DeviceNotFound(format!("unknown source id: {}", source_id)))?;
+        source.muted = muted;
+        Ok(())
+    }
+
+    /// Solos or un-solos `source_id`. While any source is soloed,
+    /// `mix_audio_sources` mixes only soloed (and unmuted) sources instead
+    /// of every active one - see `mix_audio_sources`'s `any_solo` check.
+    pub async fn set_source_solo(&self, source_id: &str, solo: bool) -> Result<(), CaptureError> {
+        let mut sources = self.sources.lock().await;
+        let source = sources.get_mut(source_id)
+            .ok_or_else(|| CaptureError::DeviceNotFound(format!("unknown source id: {}", source_id)))?;
+        source.solo = solo;
+        Ok(())
+    }
+
+    /// Get separate audio data from a specific source
     pub async fn get_source_audio(&self, source_id: &str, max_samples: Option<usize>) -> Vec<f32> {
-        let mut buffers = self.audio_buffers.lock().await;
-        
-        if let Some(buffer) = buffers.get_mut(source_id) {
-            let samples_to_take = max_samples.map(|max| max.min(buffer.len())).unwrap_or(buffer.len());
-            let result = buffer.drain(0..samples_to_take).collect();
-            result
+        let buffers = self.source_buffers.lock().await;
+
+        if let Some(buf) = buffers.get(source_id) {
+            if let Ok(mut consumer) = buf.consumer.lock() {
+                let samples_to_take = max_samples
+                    .map(|max| max.min(consumer.occupied_len()))
+                    .unwrap_or_else(|| consumer.occupied_len());
+                consumer.pop_iter().take(samples_to_take).collect()
+            } else {
+                Vec::new()
+            }
         } else {
             Vec::new()
         }
     }
 
+    /// Drain this source's buffered audio and write it to `path` as a
+    /// 16-bit PCM WAV file, using the source's native channel count and the
+    /// capture's configured sample rate - mirrors
+    /// `FileSystemManager::save_recording_wav`, but reads straight from the
+    /// live ring buffer instead of a pre-recorded blob, so a participant's
+    /// stream can be archived independently of the mixed output.
+    pub async fn export_source_wav(
+        &self,
+        source_id: &str,
+        path: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let channels = {
+            let sources = self.sources.lock().await;
+            sources
+                .get(source_id)
+                .ok_or_else(|| format!("unknown source id: {}", source_id))?
+                .channels
+        };
+        let samples = self.get_source_audio(source_id, None).await;
+        write_wav(path, &samples, self.config.sample_rate, channels)
+    }
+
+    /// Drain the mixed-output buffer and write it to `path` as a 16-bit PCM
+    /// WAV file, using the capture's configured sample rate/channel count -
+    /// the combined-meeting-audio counterpart to `export_source_wav`.
+    pub async fn export_mixed_wav(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let samples = self.get_mixed_audio(None).await;
+        write_wav(path, &samples, self.config.sample_rate, self.config.channels)
+    }
+
     /// Get recording status
     pub async fn get_status(&self) -> serde_json::Value {
         let active_streams = self.active_streams.lock().await;
-        let buffers = self.audio_buffers.lock().await;
-        
+        let buffers = self.source_buffers.lock().await;
+        let sources = self.sources.lock().await;
+
         let buffer_sizes: HashMap<String, usize> = buffers.iter()
-            .map(|(id, buffer)| (id.clone(), buffer.len()))
+            .map(|(id, buf)| {
+                let occupied = buf.consumer.lock().map(|c| c.occupied_len()).unwrap_or(0);
+                (id.clone(), occupied)
+            })
+            .collect();
+        let dropped_samples: HashMap<String, u64> = buffers.iter()
+            .map(|(id, buf)| (id.clone(), buf.dropped_samples.load(Ordering::Relaxed)))
+            .collect();
+        let gains: HashMap<String, f32> = sources.iter()
+            .map(|(id, source)| (id.clone(), source.gain))
+            .collect();
+        let muted: HashMap<String, bool> = sources.iter()
+            .map(|(id, source)| (id.clone(), source.muted))
+            .collect();
+        let solo: HashMap<String, bool> = sources.iter()
+            .map(|(id, source)| (id.clone(), source.solo))
+            .collect();
+        let is_active: HashMap<String, bool> = buffers.iter()
+            .map(|(id, buf)| (id.clone(), buf.vad_active.load(Ordering::Relaxed)))
+            .collect();
+        let speech_seconds: HashMap<String, f64> = buffers.iter()
+            .map(|(id, buf)| {
+                let channels = sources.get(id).map(|s| s.channels.max(1)).unwrap_or(1) as f64;
+                let seconds = buf.speech_samples.load(Ordering::Relaxed) as f64
+                    / (self.config.sample_rate as f64 * channels);
+                (id.clone(), seconds)
+            })
             .collect();
 
         serde_json::json!({
@@ -653,11 +2231,175 @@ impl MultiSourceAudioCapture {
             "active_sources": active_streams.len(),
             "source_ids": *active_streams,
             "buffer_sizes": buffer_sizes,
-            "total_samples": buffer_sizes.values().sum::<usize>()
+            "dropped_samples": dropped_samples,
+            "total_samples": buffer_sizes.values().sum::<usize>(),
+            "gains": gains,
+            "muted": muted,
+            "solo": solo,
+            "is_active": is_active,
+            "speech_seconds": speech_seconds
         })
     }
+}
+
+/// Raw Core Audio bindings for the "process tap" system-audio loopback
+/// introduced in macOS 14.2, just enough surface to create and tear down a
+/// tap + aggregate device - modeled on cubeb-coreaudio's
+/// `aggregate_device.cpp`, which drives the same two APIs to build a
+/// loopback-capable input device out of a tap and the real output device.
+/// `AudioHardwareCreateAggregateDevice`/`AudioHardwareDestroyAggregateDevice`
+/// are long-standing public Core Audio API; `CATapDescription` and
+/// `AudioHardwareCreateProcessTap`/`AudioHardwareDestroyProcessTap` are the
+/// 14.2 additions and are only reachable through the Objective-C runtime
+/// since Apple ships `CATapDescription` as an Objective-C class with no C
+/// struct equivalent.
+#[cfg(target_os = "macos")]
+mod coreaudio_tap {
+    use std::ffi::{c_void, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    pub type AudioObjectID = u32;
+    type OSStatus = i32;
+
+    #[link(name = "CoreAudio", kind = "framework")]
+    extern "C" {
+        fn AudioHardwareCreateAggregateDevice(in_description: *const c_void, out_device_id: *mut AudioObjectID) -> OSStatus;
+        fn AudioHardwareDestroyAggregateDevice(in_device_id: AudioObjectID) -> OSStatus;
+        fn AudioHardwareCreateProcessTap(in_description: *const c_void, out_tap_id: *mut AudioObjectID) -> OSStatus;
+        fn AudioHardwareDestroyProcessTap(in_tap_id: AudioObjectID) -> OSStatus;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringCreateWithCString(alloc: *const c_void, c_str: *const c_char, encoding: u32) -> *const c_void;
+        fn CFDictionaryCreate(
+            alloc: *const c_void,
+            keys: *const *const c_void,
+            values: *const *const c_void,
+            num_values: isize,
+            key_callbacks: *const c_void,
+            value_callbacks: *const c_void,
+        ) -> *const c_void;
+        fn CFRelease(cf: *const c_void);
+        static kCFTypeDictionaryKeyCallBacks: c_void;
+        static kCFTypeDictionaryValueCallBacks: c_void;
+    }
+
+    extern "C" {
+        fn objc_getClass(name: *const c_char) -> *const c_void;
+        fn sel_registerName(name: *const c_char) -> *const c_void;
+        fn objc_msgSend(receiver: *const c_void, selector: *const c_void, ...) -> *const c_void;
+    }
 
+    const K_CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+
+    fn cfstr(s: &str) -> *const c_void {
+        let c = CString::new(s).unwrap();
+        unsafe { CFStringCreateWithCString(ptr::null(), c.as_ptr(), K_CF_STRING_ENCODING_UTF8) }
+    }
+
+    /// Tap + aggregate device created for one `start_coreaudio_loopback`
+    /// session; `stop_recording` tears both down in reverse order.
+    pub struct TapHandles {
+        pub tap_id: AudioObjectID,
+        pub aggregate_id: AudioObjectID,
+        /// The aggregate device's CoreAudio UID - also its cpal device name,
+        /// since cpal's macOS backend names input devices by UID.
+        pub aggregate_device_uid: String,
+    }
+
+    /// Builds a `CATapDescription` for a global (system-wide) stereo mix tap
+    /// via the Objective-C runtime, taps it with
+    /// `AudioHardwareCreateProcessTap`, then wraps the tap and the default
+    /// output device into a new aggregate device via
+    /// `AudioHardwareCreateAggregateDevice` so cpal can open it like any
+    /// other input.
+    pub fn create_system_tap_aggregate(aggregate_name: &str) -> Result<TapHandles, String> {
+        unsafe {
+            let class = objc_getClass(CString::new("CATapDescription").unwrap().as_ptr());
+            if class.is_null() {
+                return Err("CATapDescription unavailable - requires macOS 14.2+".to_string());
+            }
 
+            let alloc_sel = sel_registerName(CString::new("alloc").unwrap().as_ptr());
+            let init_sel = sel_registerName(
+                CString::new("initStereoGlobalTapButExcludeProcesses:").unwrap().as_ptr(),
+            );
+            let instance = objc_msgSend(class, alloc_sel);
+            // An empty exclude-list taps every process's output.
+            let empty_exclude_list: *const c_void = ptr::null();
+            let description = objc_msgSend(instance, init_sel, empty_exclude_list);
+            if description.is_null() {
+                return Err("Failed to initialize CATapDescription".to_string());
+            }
+
+            let mut tap_id: AudioObjectID = 0;
+            let status = AudioHardwareCreateProcessTap(description, &mut tap_id);
+            if status != 0 {
+                return Err(format!("AudioHardwareCreateProcessTap failed: OSStatus {}", status));
+            }
+
+            let uid_sel = sel_registerName(CString::new("UID").unwrap().as_ptr());
+            let tap_uid_cf = objc_msgSend(description, uid_sel);
+
+            let aggregate_uid = format!("com.dgmeets.system-audio-tap.{}", tap_id);
+            let name_cf = cfstr(aggregate_name);
+            let uid_cf = cfstr(&aggregate_uid);
+            let name_key = cfstr("name");
+            let uid_key = cfstr("uid");
+            let tap_list_key = cfstr("taps");
+            let tap_uid_key = cfstr("uid");
+
+            let tap_entry_keys = [tap_uid_key];
+            let tap_entry_values = [tap_uid_cf];
+            let tap_entry = CFDictionaryCreate(
+                ptr::null(),
+                tap_entry_keys.as_ptr(),
+                tap_entry_values.as_ptr(),
+                1,
+                &kCFTypeDictionaryKeyCallBacks,
+                &kCFTypeDictionaryValueCallBacks,
+            );
+
+            let keys = [name_key, uid_key, tap_list_key];
+            let values = [name_cf, uid_cf, tap_entry];
+            let description_dict = CFDictionaryCreate(
+                ptr::null(),
+                keys.as_ptr(),
+                values.as_ptr(),
+                keys.len() as isize,
+                &kCFTypeDictionaryKeyCallBacks,
+                &kCFTypeDictionaryValueCallBacks,
+            );
+
+            let mut aggregate_id: AudioObjectID = 0;
+            let status = AudioHardwareCreateAggregateDevice(description_dict, &mut aggregate_id);
+            CFRelease(description_dict);
+            CFRelease(tap_entry);
+            if status != 0 {
+                let _ = AudioHardwareDestroyProcessTap(tap_id);
+                return Err(format!("AudioHardwareCreateAggregateDevice failed: OSStatus {}", status));
+            }
+
+            Ok(TapHandles { tap_id, aggregate_id, aggregate_device_uid: aggregate_uid })
+        }
+    }
+
+    /// Tears down an aggregate device and its backing tap, in that order -
+    /// the aggregate device holds a reference to the tap while it's alive.
+    pub fn destroy_system_tap_aggregate(handles: TapHandles) {
+        unsafe {
+            let status = AudioHardwareDestroyAggregateDevice(handles.aggregate_id);
+            if status != 0 {
+                eprintln!("⚠️ AudioHardwareDestroyAggregateDevice failed: OSStatus {}", status);
+            }
+            let status = AudioHardwareDestroyProcessTap(handles.tap_id);
+            if status != 0 {
+                eprintln!("⚠️ AudioHardwareDestroyProcessTap failed: OSStatus {}", status);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -680,4 +2422,51 @@ mod tests {
         let _sources = capture.discover_sources().await;
         // Just test that it doesn't panic
     }
+
+    #[tokio::test]
+    async fn test_synthetic_source_populates_buffer() {
+        let mut config = MultiAudioConfig::default();
+        config.vad_enabled = false;
+        let capture = MultiSourceAudioCapture::new(config);
+
+        capture
+            .add_synthetic_source(
+                "synthetic_sine",
+                SyntheticSignal::Sine { frequency: 440.0, amplitude: 0.5 },
+                44100,
+                0.1,
+            )
+            .await
+            .unwrap();
+
+        let samples = capture.get_source_audio("synthetic_sine", None).await;
+        assert!(!samples.is_empty(), "synthetic source should have queued audio");
+        assert!(samples.iter().all(|s| s.abs() <= 0.51), "sine shouldn't exceed its amplitude");
+    }
+
+    #[tokio::test]
+    async fn test_mixing_two_silent_synthetic_sources_stays_silent() {
+        let mut config = MultiAudioConfig::default();
+        config.vad_enabled = false;
+        config.channels = 1;
+        let capture = MultiSourceAudioCapture::new(config);
+
+        capture.add_synthetic_source("a", SyntheticSignal::Silence, 44100, 0.05).await.unwrap();
+        capture.add_synthetic_source("b", SyntheticSignal::Silence, 44100, 0.05).await.unwrap();
+
+        let mixed = capture.get_mixed_audio(None).await;
+        assert!(!mixed.is_empty());
+        assert!(mixed.iter().all(|&s| s == 0.0), "mixing two silent sources should stay silent");
+    }
+
+    #[tokio::test]
+    async fn test_vad_gates_silent_synthetic_source() {
+        let config = MultiAudioConfig::default();
+        let capture = MultiSourceAudioCapture::new(config);
+
+        capture.add_synthetic_source("quiet", SyntheticSignal::Silence, 44100, 0.2).await.unwrap();
+
+        let status = capture.get_status().await;
+        assert_eq!(status["is_active"]["quiet"], false);
+    }
 }
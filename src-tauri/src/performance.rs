@@ -1,5 +1,6 @@
 use std::time::Instant;
 use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PerformanceMetrics {
@@ -7,11 +8,28 @@ pub struct PerformanceMetrics {
     pub memory_usage: u64,
     pub cpu_usage: f64,
     pub file_operations_time: f64,
+    /// Samples the capture loop's discontinuity check found missing versus
+    /// what elapsed wall time said should have arrived - see
+    /// `record_underrun_check` and `plugins::audio_capture`'s `audio:underrun`
+    /// event.
+    pub samples_dropped: u64,
+    /// Number of discontinuity checks that came up materially short.
+    pub underrun_events: u64,
+    /// Running average of `write_chunk`'s wall time, in milliseconds.
+    pub avg_chunk_write_ms: f64,
 }
 
+/// Discontinuity checks only count as an underrun once the shortfall clears
+/// this fraction of the expected sample count, so ordinary scheduling jitter
+/// between `level_ticker` ticks doesn't read as dropped audio.
+const UNDERRUN_SLACK: f64 = 0.2;
+
 pub struct PerformanceMonitor {
     start_time: Instant,
     metrics: PerformanceMetrics,
+    system: System,
+    pid: Pid,
+    chunk_writes_observed: u64,
 }
 
 impl PerformanceMonitor {
@@ -23,7 +41,15 @@ impl PerformanceMonitor {
                 memory_usage: 0,
                 cpu_usage: 0.0,
                 file_operations_time: 0.0,
+                samples_dropped: 0,
+                underrun_events: 0,
+                avg_chunk_write_ms: 0.0,
             },
+            system: System::new_with_specifics(
+                RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
+            ),
+            pid: Pid::from_u32(std::process::id()),
+            chunk_writes_observed: 0,
         }
     }
 
@@ -39,21 +65,14 @@ impl PerformanceMonitor {
         self.metrics.file_operations_time = start.elapsed().as_millis() as f64;
     }
 
+    /// Refreshes this process's real memory (RSS) and CPU usage via
+    /// `sysinfo` - cross-platform, so unlike the mocked values this used to
+    /// return there's no per-OS branch left to maintain.
     pub async fn update_system_metrics(&mut self) {
-        // Get system memory and CPU usage
-        #[cfg(target_os = "windows")]
-        {
-            self.update_windows_metrics().await;
-        }
-
-        #[cfg(target_os = "macos")]
-        {
-            self.update_macos_metrics().await;
-        }
-
-        #[cfg(target_os = "linux")]
-        {
-            self.update_linux_metrics().await;
+        self.system.refresh_processes(ProcessesToUpdate::Some(&[self.pid]), true);
+        if let Some(process) = self.system.process(self.pid) {
+            self.metrics.memory_usage = process.memory();
+            self.metrics.cpu_usage = process.cpu_usage() as f64;
         }
     }
 
@@ -61,25 +80,29 @@ impl PerformanceMonitor {
         &self.metrics
     }
 
-    #[cfg(target_os = "windows")]
-    async fn update_windows_metrics(&mut self) {
-        // Use Windows API to get system metrics
-        // Implementation would use winapi crate
-        self.metrics.memory_usage = 100 * 1024 * 1024; // Mock 100MB
-        self.metrics.cpu_usage = 15.5; // Mock 15.5%
-    }
-
-    #[cfg(target_os = "macos")]
-    async fn update_macos_metrics(&mut self) {
-        // Use macOS system APIs
-        self.metrics.memory_usage = 120 * 1024 * 1024; // Mock 120MB
-        self.metrics.cpu_usage = 12.3; // Mock 12.3%
+    /// Records one capture-loop discontinuity check: `expected` samples given
+    /// elapsed wall time and sample rate versus `delivered` actually pulled
+    /// from the ring buffer since the last check. Returns whether this check
+    /// counted as an underrun (shortfall past `UNDERRUN_SLACK`).
+    pub fn record_underrun_check(&mut self, expected: u64, delivered: u64) -> bool {
+        if expected == 0 {
+            return false;
+        }
+        let shortfall = expected.saturating_sub(delivered);
+        if shortfall as f64 / expected as f64 > UNDERRUN_SLACK {
+            self.metrics.samples_dropped += shortfall;
+            self.metrics.underrun_events += 1;
+            true
+        } else {
+            false
+        }
     }
 
-    #[cfg(target_os = "linux")]
-    async fn update_linux_metrics(&mut self) {
-        // Use Linux /proc filesystem
-        self.metrics.memory_usage = 90 * 1024 * 1024; // Mock 90MB
-        self.metrics.cpu_usage = 18.7; // Mock 18.7%
+    /// Folds one `write_chunk` duration (milliseconds) into the running
+    /// average.
+    pub fn record_chunk_write(&mut self, ms: f64) {
+        self.chunk_writes_observed += 1;
+        let n = self.chunk_writes_observed as f64;
+        self.metrics.avg_chunk_write_ms += (ms - self.metrics.avg_chunk_write_ms) / n;
     }
 }
@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, sync::Arc, time::{Duration}};
-use tokio::{sync::Mutex, time::sleep};
-use anyhow::{Result, anyhow};
+use std::{path::PathBuf, sync::Arc, time::{Duration, Instant}};
+use tokio::sync::{mpsc, oneshot, Mutex};
+use anyhow::{anyhow, Result};
 use uuid::Uuid;
 use tauri::{Emitter, Manager};
 
-use crate::multi_audio::{MultiSourceAudioCapture, MultiAudioConfig, AudioSource, AudioSourceType};
+use crate::chunk_codec::{self, ChunkCodec};
+use crate::multi_audio::{AudioSource, AudioSourceType, MultiAudioConfig, MultiSourceAudioCapture};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChunkEvent {
@@ -17,221 +18,1407 @@ pub struct ChunkEvent {
     pub duration_ms: u128,
     pub bytes: u64,
     pub kind: String, // mic | system | mix
+    /// True for the optional per-track (mic-only / system-only) chunks
+    /// emitted alongside a mixed session when `emit_separate` is enabled, so
+    /// the forwarder can route them to `audio:chunk_mic`/`audio:chunk_sys`
+    /// instead of the main `audio:chunk` topic.
+    pub separate_track: bool,
+    /// "wav" | "opus" | "flac", matching `path`'s extension, so the
+    /// coordinator and frontend know how to decode the file. May differ
+    /// from the session's configured codec if Opus encoding failed and
+    /// `write_chunk` fell back to WAV for this chunk.
+    pub codec: String,
+    /// Bits/sec the chunk was encoded at, only set when `codec == "opus"`.
+    pub opus_bitrate: Option<i32>,
+    /// "i16" | "i24" | "f32" - only meaningful for `codec == "wav"`; Opus and
+    /// FLAC chunks keep their own fixed internal formats.
+    pub sample_format: String,
+    pub bits_per_sample: u16,
+}
+
+/// PCM sample encoding `write_wav_chunk` writes each `chunk_NNNN.wav` file
+/// in - selectable per session via `AudioChunker::set_sample_format` /
+/// `ac_set_sample_format`. `Int16` (the default) matches what most capture
+/// backends hand over already; `Int24`/`Float32` trade larger files for the
+/// headroom `Int16`'s clamp-and-quantize step throws away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SampleFormat {
+    Int16,
+    /// 24-bit samples packed into a 32-bit container, as WAV's `fmt ` chunk
+    /// expects for `bits_per_sample = 24`.
+    Int24,
+    /// Native float PCM - writes samples straight through with no
+    /// clamp-and-quantize step, so nothing outside `[-1, 1]` gets clipped.
+    Float32,
+}
+
+impl SampleFormat {
+    pub fn bits_per_sample(self) -> u16 {
+        match self {
+            SampleFormat::Int16 => 16,
+            SampleFormat::Int24 => 24,
+            SampleFormat::Float32 => 32,
+        }
+    }
+
+    fn hound_sample_format(self) -> hound::SampleFormat {
+        match self {
+            SampleFormat::Int16 | SampleFormat::Int24 => hound::SampleFormat::Int,
+            SampleFormat::Float32 => hound::SampleFormat::Float,
+        }
+    }
+
+    /// Label surfaced on `ChunkEvent.sample_format` for the frontend.
+    pub fn label(self) -> &'static str {
+        match self {
+            SampleFormat::Int16 => "i16",
+            SampleFormat::Int24 => "i24",
+            SampleFormat::Float32 => "f32",
+        }
+    }
+}
+
+impl Default for SampleFormat {
+    fn default() -> Self {
+        SampleFormat::Int16
+    }
+}
+
+/// Thresholds for the FFT-based voice-activity pass the actor runs over
+/// each accumulated chunk before writing it to disk.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VadConfig {
+    pub frame_ms: f32,
+    pub speech_band_low_hz: f32,
+    pub speech_band_high_hz: f32,
+    /// Minimum speech-band/total power ratio for a frame to count as voiced.
+    pub band_ratio_threshold: f32,
+    /// Minimum fraction of voiced frames in a chunk for it to be kept.
+    pub min_voiced_chunk_ratio: f32,
+    /// Adaptive-chunking mode only: minimum accumulated speech a pending
+    /// chunk must have before a silence gap is allowed to close it.
+    pub min_chunk_secs: f32,
+    /// Adaptive-chunking mode only: hard cap on a chunk's length so a
+    /// continuous talker still gets split.
+    pub max_chunk_secs: f32,
+    /// Adaptive-chunking mode only: consecutive trailing silence required to
+    /// close a chunk at the last speech-end frame.
+    pub silence_hold_ms: f32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            frame_ms: 25.0,
+            speech_band_low_hz: 300.0,
+            speech_band_high_hz: 3400.0,
+            band_ratio_threshold: 0.35,
+            min_voiced_chunk_ratio: 0.1,
+            min_chunk_secs: 2.0,
+            max_chunk_secs: 30.0,
+            silence_hold_ms: 500.0,
+        }
+    }
+}
+
+/// Frame size in samples for `cfg.frame_ms` at `sample_rate`, shared by
+/// `analyze_vad_frames` and the adaptive-chunking flush logic so both agree
+/// on frame boundaries.
+fn vad_frame_size(sample_rate: u32, cfg: &VadConfig) -> usize {
+    ((cfg.frame_ms / 1000.0) * sample_rate as f32).round() as usize
+}
+
+/// Per-frame result of `analyze_vad_frames`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FrameVad {
+    pub rms: f32,
+    pub voiced: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AudioLevelEvent {
+    pub rms: f32,
+    pub voiced: bool,
+}
+
+/// One active source's level as of the ~100ms metering tick in
+/// `spawn_audio_actor` - the payload of the `audio:levels` event, batched
+/// across all active sources rather than fired once per source.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SourceLevelEvent {
+    pub source_id: String,
+    pub kind: String,
+    pub peak: f32,
+    pub rms: f32,
+}
+
+/// Dead-mic gate: if the microphone source's RMS stays below `threshold`
+/// for `hold_secs` while recording, the actor pushes a "Microphone appears
+/// silent" warning (once per silence episode, not every tick). Tuned via
+/// `ac_set_mic_threshold`.
+#[derive(Debug, Clone, Copy)]
+pub struct MicThresholdConfig {
+    pub threshold: f32,
+    pub hold_secs: f32,
+}
+
+impl Default for MicThresholdConfig {
+    fn default() -> Self {
+        Self { threshold: 0.01, hold_secs: 10.0 }
+    }
+}
+
+/// Splits `samples` into `cfg.frame_ms` frames, applies a Hann window, and
+/// runs a real FFT on each frame to classify it as voiced or not: a frame
+/// counts as voiced when its speech-band/total power ratio clears
+/// `band_ratio_threshold` *and* its total power clears the adaptively
+/// tracked noise floor. `noise_floor`/`floor_initialized` persist across
+/// calls so the floor tracks ambient noise over the life of a session, the
+/// same asymmetric-EMA shape `SourceVad` uses in `multi_audio.rs`.
+fn analyze_vad_frames(
+    samples: &[f32],
+    sample_rate: u32,
+    cfg: &VadConfig,
+    noise_floor: &mut f32,
+    floor_initialized: &mut bool,
+) -> Vec<FrameVad> {
+    let frame_size = vad_frame_size(sample_rate, cfg);
+    if frame_size == 0 || samples.len() < frame_size {
+        return Vec::new();
+    }
+
+    let mut planner = realfft::RealFftPlanner::<f32>::new();
+    let r2c = planner.plan_fft_forward(frame_size);
+    let window: Vec<f32> = (0..frame_size)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / frame_size as f32).cos())
+        .collect();
+
+    let bin_hz = sample_rate as f32 / frame_size as f32;
+    let low_bin = ((cfg.speech_band_low_hz / bin_hz).ceil() as usize).max(1);
+    let high_bin = ((cfg.speech_band_high_hz / bin_hz).floor() as usize).min(frame_size / 2);
+
+    let mut results = Vec::with_capacity(samples.len() / frame_size);
+    let mut offset = 0;
+    while offset + frame_size <= samples.len() {
+        let frame = &samples[offset..offset + frame_size];
+        let mut windowed: Vec<f32> = frame.iter().zip(window.iter()).map(|(s, w)| s * w).collect();
+        let mut spectrum = r2c.make_output_vec();
+
+        if r2c.process(&mut windowed, &mut spectrum).is_ok() {
+            // Bin 0 is DC; ignore it per the spec.
+            let total_power: f32 = spectrum.iter().skip(1).map(|c| c.norm_sqr()).sum();
+            let band_power: f32 = if high_bin > low_bin {
+                spectrum[low_bin..=high_bin].iter().map(|c| c.norm_sqr()).sum()
+            } else {
+                0.0
+            };
+            let ratio = if total_power > 0.0 { band_power / total_power } else { 0.0 };
+
+            if !*floor_initialized {
+                *noise_floor = total_power;
+                *floor_initialized = true;
+            } else {
+                *noise_floor = (*noise_floor * 1.02).min(0.95 * *noise_floor + 0.05 * total_power);
+            }
+
+            let voiced = ratio > cfg.band_ratio_threshold && total_power > *noise_floor * 2.0;
+            let rms = (frame.iter().map(|s| s * s).sum::<f32>() / frame_size as f32).sqrt();
+            results.push(FrameVad { rms, voiced });
+        }
+
+        offset += frame_size;
+    }
+
+    results
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SessionInfo {
+    pub session_id: Option<String>,
+    pub session_dir: Option<String>,
+}
+
+/// Written as `session.json` alongside a session's chunks at start time and
+/// refreshed after every chunk lands, so a crash mid-recording leaves enough
+/// on disk to resume capture with the exact original parameters instead of
+/// guessing them from the chunk files alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionManifest {
+    pub session_id: String,
+    pub kind: String,
+    pub sample_rate: u32,
+    pub chunk_secs: u64,
+    pub active_sources: Vec<String>,
+    pub last_chunk_index: u64,
+    pub started_at_ms: u128,
+}
+
+/// A session directory found by `scan_recoverable_sessions` that has a
+/// `session.json` manifest but no `final.wav`, i.e. was left behind by a
+/// crash or a forced quit mid-recording.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoverableSession {
+    pub session_id: String,
+    pub session_dir: String,
+    pub manifest: SessionManifest,
+}
+
+/// Commands accepted by the audio-capture actor spawned in
+/// `AudioChunker::new`. The actor owns the only `MultiSourceAudioCapture`
+/// and all session state; every `AudioChunker` method (and so every `ac_*`
+/// Tauri command) is just a sender on this channel. An `ac_*` handler still
+/// holds the `Arc<Mutex<AudioChunker>>` lock across the `.await` of its own
+/// message's round trip, but never across the capture loop itself - and
+/// `Pause`/`Resume` can interrupt the capture loop mid-session instead of
+/// waiting for the next chunk boundary.
+pub enum AudioControlMessage {
+    StartMic { reply: oneshot::Sender<Result<String, String>> },
+    StartSystem { reply: oneshot::Sender<Result<String, String>> },
+    /// `sources: None` auto-discovers the default mic + system pair, same as
+    /// the original `start_mix`.
+    StartMix { sources: Option<Vec<String>>, reply: oneshot::Sender<Result<String, String>> },
+    /// Re-attaches to a crashed/orphaned session found by
+    /// `scan_recoverable_sessions`, continuing chunk numbering from its
+    /// manifest's `last_chunk_index` instead of starting a new session.
+    ResumeSession { session_id: String, reply: oneshot::Sender<Result<String, String>> },
+    Pause { reply: oneshot::Sender<Result<(), String>> },
+    Resume { reply: oneshot::Sender<Result<(), String>> },
+    SetChunkSecs { secs: u64, reply: oneshot::Sender<Result<(), String>> },
+    Stop { reply: oneshot::Sender<Result<(), String>> },
+    SetVadConfig { config: VadConfig, reply: oneshot::Sender<Result<(), String>> },
+    /// Tunes the dead-mic gate driving the "Microphone appears silent"
+    /// warning (see `MicThresholdConfig`).
+    SetMicThreshold { threshold: f32, hold_secs: f32, reply: oneshot::Sender<Result<(), String>> },
+    /// Sets the gain applied to every active source (mic + system), and
+    /// remembered so sources started afterwards pick it up too.
+    SetInputSensitivity { gain: f32, reply: oneshot::Sender<Result<(), String>> },
+    /// Sets the RMS-level silence gate a fixed-interval chunk must clear to
+    /// be written: a chunk whose frames are *all* below `threshold` for at
+    /// least `hangover_ms` is dropped before it reaches Whisper, same as
+    /// `should_write`'s existing band-ratio VAD but keyed on raw level
+    /// instead of speech-band power. `threshold = None` disables the gate.
+    SetVadThreshold { threshold: Option<f32>, hangover_ms: f32, reply: oneshot::Sender<Result<(), String>> },
+    ToggleSeparateEmission { enabled: bool, reply: oneshot::Sender<Result<(), String>> },
+    /// Grows or shrinks the active source set mid-session: sources not
+    /// already active are started fresh, sources no longer listed are
+    /// dropped from the mix - no session restart, so `chunk_index`/
+    /// `session_id` keep running. Only meaningful once a session is open;
+    /// errors if none is.
+    SetActiveSources { sources: Vec<String>, reply: oneshot::Sender<Result<(), String>> },
+    /// Switches chunk boundaries between the fixed `chunk_secs` timer and
+    /// VAD-closed speech/silence segments (see the adaptive-chunking block in
+    /// `spawn_audio_actor`).
+    ToggleAdaptiveChunking { enabled: bool, reply: oneshot::Sender<Result<(), String>> },
+    /// Changes the codec chunks are written in; `opus_bitrate` is only
+    /// consulted when `codec` is `ChunkCodec::Opus`.
+    SetChunkFormat { codec: ChunkCodec, opus_bitrate: i32, reply: oneshot::Sender<Result<(), String>> },
+    /// Changes the PCM format/channel count `write_wav_chunk` writes in;
+    /// only meaningful while `ChunkCodec::Wav` is selected.
+    SetSampleFormat { format: SampleFormat, channels: u16, reply: oneshot::Sender<Result<(), String>> },
+    GetSessionInfo { reply: oneshot::Sender<SessionInfo> },
+    GetDevices { reply: oneshot::Sender<Result<Vec<AudioSource>, String>> },
+    /// Delivered once, right after the Tauri app finishes building (see
+    /// `AudioChunker::set_app_handle`), so the actor can resolve
+    /// `app_data_dir()` for session directories.
+    SetAppHandle(tauri::AppHandle),
+}
+
+/// Status pushed out of the actor. A dedicated forwarder task (spawned from
+/// `AudioChunker::set_app_handle`) drains this channel into `AppHandle::emit`
+/// and the coordinator, which keeps the capture loop itself independently
+/// testable: it can be driven by feeding `AudioControlMessage`s and
+/// asserting on what comes out here. Starting or resuming a session is the
+/// exception: it resolves the session directory through the `AppHandle`
+/// delivered by `SetAppHandle`, and fails with "App handle not set" without one.
+#[derive(Debug, Clone)]
+pub enum AudioStatusMessage {
+    ChunkReady(ChunkEvent),
+    Level { rms: f32, voiced: bool },
+    Error(String),
+    Stopped,
+    Paused,
+    Resumed,
+    Levels(Vec<SourceLevelEvent>),
+    /// A user-facing warning to relay through `NotificationManager` as well
+    /// as an `audio:warning` event - currently only the dead-mic gate raises
+    /// this.
+    Warning(String),
+    /// One discontinuity check: `expected` samples given elapsed wall time
+    /// and `sample_rate` versus `delivered` actually pulled from the ring
+    /// buffer since the last check. Forwarded into `PerformanceMonitor`;
+    /// becomes an `audio:underrun` event when the shortfall is material.
+    Underrun { expected: u64, delivered: u64 },
+    /// Wall-clock duration (milliseconds) of one `write_chunk` call, folded
+    /// into `PerformanceMetrics::avg_chunk_write_ms`.
+    ChunkWriteTimed(f64),
 }
 
 pub struct AudioChunker {
+    control_tx: mpsc::Sender<AudioControlMessage>,
+    status_rx: Option<mpsc::Receiver<AudioStatusMessage>>,
     app_handle: Option<tauri::AppHandle>,
-    capture: Arc<MultiSourceAudioCapture>,
-    session_id: Option<String>,
-    session_dir: Option<PathBuf>,
-    chunk_secs: u64,
-    sample_rate: u32,
-    active_sources: Vec<String>,
-    kind: Option<String>,
-    start_instant_ms: u128,
-    chunk_index: u64,
-    emit_separate: bool,
 }
 
 impl AudioChunker {
     pub fn new(sample_rate: u32, chunk_secs: u64) -> Self {
-        let config = MultiAudioConfig {
-            sample_rate,
-            channels: 1,
-            buffer_size: 1024,
-            max_sources: 4,
-            mix_output: true,
-        };
+        let (control_tx, status_rx) = spawn_audio_actor(sample_rate, chunk_secs);
         Self {
+            control_tx,
+            status_rx: Some(status_rx),
             app_handle: None,
-            capture: Arc::new(MultiSourceAudioCapture::new(config)),
-            session_id: None,
-            session_dir: None,
-            chunk_secs,
-            sample_rate,
-            active_sources: Vec::new(),
-            kind: None,
-            start_instant_ms: 0,
-            chunk_index: 0,
-            emit_separate: false,
         }
     }
 
+    /// Hands the actor its `AppHandle` (for session-directory resolution)
+    /// and spawns the forwarder task that turns `AudioStatusMessage`s into
+    /// Tauri events and coordinator calls.
     pub fn set_app_handle(&mut self, handle: tauri::AppHandle) {
+        let tx = self.control_tx.clone();
+        let handle_for_actor = handle.clone();
+        tokio::spawn(async move {
+            let _ = tx.send(AudioControlMessage::SetAppHandle(handle_for_actor)).await;
+        });
+
+        if let Some(status_rx) = self.status_rx.take() {
+            spawn_status_forwarder(handle.clone(), status_rx);
+        }
         self.app_handle = Some(handle);
     }
 
+    pub fn set_vad_config(&self, vad_config: VadConfig) {
+        let tx = self.control_tx.clone();
+        tokio::spawn(async move {
+            let (reply, _) = oneshot::channel();
+            let _ = tx.send(AudioControlMessage::SetVadConfig { config: vad_config, reply }).await;
+        });
+    }
+
+    async fn send_control<T>(&self, build: impl FnOnce(oneshot::Sender<T>) -> AudioControlMessage) -> Result<T> {
+        let (reply, rx) = oneshot::channel();
+        self.control_tx
+            .send(build(reply))
+            .await
+            .map_err(|_| anyhow!("audio capture actor is not running"))?;
+        rx.await.map_err(|_| anyhow!("audio capture actor dropped its reply"))
+    }
+
     pub async fn get_devices(&self) -> Result<Vec<AudioSource>> {
-        self.capture.discover_sources().await
+        self.send_control(|reply| AudioControlMessage::GetDevices { reply })
+            .await?
+            .map_err(|e| anyhow!(e))
     }
 
-    pub async fn start_mic(&mut self) -> Result<String> {
-        let sources = self.capture.discover_sources().await?;
-        let mic = sources.into_iter().find(|s| s.device_type == AudioSourceType::Microphone)
-            .ok_or_else(|| anyhow!("No microphone source found"))?;
-        self.start_session(vec![mic.id], "mic").await
+    pub async fn get_session_info(&self) -> SessionInfo {
+        self.send_control(|reply| AudioControlMessage::GetSessionInfo { reply })
+            .await
+            .unwrap_or_default()
     }
 
-    pub async fn start_system(&mut self) -> Result<String> {
-        let sources = self.capture.discover_sources().await?;
-        let sys = sources.into_iter().find(|s| s.device_type == AudioSourceType::SystemAudio)
-            .ok_or_else(|| anyhow!("No system audio source found"))?;
-        self.start_session(vec![sys.id], "system").await
+    pub async fn start_mic(&self) -> Result<String> {
+        self.send_control(|reply| AudioControlMessage::StartMic { reply })
+            .await?
+            .map_err(|e| anyhow!(e))
     }
 
-    pub async fn start_mix(&mut self) -> Result<String> {
-        let sources = self.capture.discover_sources().await?;
-        let mut ids = Vec::new();
-        if let Some(mic) = sources.iter().find(|s| s.device_type == AudioSourceType::Microphone) {
-            ids.push(mic.id.clone());
-        }
-        if let Some(sys) = sources.iter().find(|s| s.device_type == AudioSourceType::SystemAudio) {
-            ids.push(sys.id.clone());
-        }
-        if ids.is_empty() {
-            return Err(anyhow!("No available sources for mix"));
-        }
-        self.start_session(ids, "mix").await
+    pub async fn start_system(&self) -> Result<String> {
+        self.send_control(|reply| AudioControlMessage::StartSystem { reply })
+            .await?
+            .map_err(|e| anyhow!(e))
     }
 
-    pub async fn stop_all(&mut self) -> Result<()> {
-        let _ = self.capture.stop_recording().await;
-        self.active_sources.clear();
-        self.kind = None;
-        self.session_id = None;
-        self.session_dir = None;
-        self.start_instant_ms = 0;
-        self.chunk_index = 0;
-        Ok(())
+    pub async fn start_mix(&self) -> Result<String> {
+        self.send_control(|reply| AudioControlMessage::StartMix { sources: None, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
     }
 
-    async fn start_session(&mut self, source_ids: Vec<String>, kind: &str) -> Result<String> {
-        let _ = self.stop_all().await;
+    pub async fn resume_session(&self, session_id: String) -> Result<String> {
+        self.send_control(|reply| AudioControlMessage::ResumeSession { session_id, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
 
-        let session_id = Uuid::new_v4().to_string();
-        let dir = self.resolve_session_dir(&session_id)?;
-        std::fs::create_dir_all(&dir)?;
+    pub async fn pause(&self) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::Pause { reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
 
-        self.capture.start_multi_recording(source_ids.clone()).await?;
+    pub async fn resume(&self) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::Resume { reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
 
-        self.start_instant_ms = chrono::Utc::now().timestamp_millis() as u128;
-        self.chunk_index = 0;
-        let app = self.app_handle.clone();
-        let capture = Arc::clone(&self.capture);
-        let session_dir = dir.clone();
-        let session_id_clone = session_id.clone();
-        let chunk_secs = self.chunk_secs;
-        let sample_rate = self.sample_rate;
-        let src_ids = source_ids.clone();
-        let kind_string = kind.to_string();
-        let emit_separate_flag = self.emit_separate;
+    pub async fn set_chunk_secs(&self, secs: u64) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetChunkSecs { secs, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
 
-        tokio::spawn(async move {
-            let mut accumulated: Vec<f32> = Vec::new();
-            let target_samples: usize = (sample_rate as usize) * (chunk_secs as usize);
-            let mut index: u64 = 0;
-            loop {
-                sleep(Duration::from_millis(200)).await;
-
-                let mut new_samples: Vec<f32> = if src_ids.len() > 1 {
-                    capture.get_mixed_audio(Some(target_samples / 5)).await
-                } else {
-                    capture.get_source_audio(&src_ids[0], Some(target_samples / 5)).await
-                };
-
-                if new_samples.is_empty() {
-                    continue;
+    pub async fn toggle_separate_emission(&self, enabled: bool) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::ToggleSeparateEmission { enabled, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn set_mic_threshold(&self, threshold: f32, hold_secs: f32) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetMicThreshold { threshold, hold_secs, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn set_input_sensitivity(&self, gain: f32) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetInputSensitivity { gain, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn set_vad_threshold(&self, threshold: Option<f32>, hangover_ms: f32) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetVadThreshold { threshold, hangover_ms, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn set_active_sources(&self, sources: Vec<String>) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetActiveSources { sources, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn toggle_adaptive_chunking(&self, enabled: bool) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::ToggleAdaptiveChunking { enabled, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn set_chunk_format(&self, codec: ChunkCodec, opus_bitrate: i32) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetChunkFormat { codec, opus_bitrate, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn set_sample_format(&self, format: SampleFormat, channels: u16) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::SetSampleFormat { format, channels, reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    pub async fn stop_all(&self) -> Result<()> {
+        self.send_control(|reply| AudioControlMessage::Stop { reply })
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+}
+
+/// Forwards status out of the actor into Tauri events (and, for finished
+/// chunks, into the coordinator), decoupling the capture loop from
+/// `AppHandle` entirely.
+fn spawn_status_forwarder(handle: tauri::AppHandle, mut status_rx: mpsc::Receiver<AudioStatusMessage>) {
+    tokio::spawn(async move {
+        while let Some(status) = status_rx.recv().await {
+            match status {
+                AudioStatusMessage::ChunkReady(event) => {
+                    if event.separate_track {
+                        let topic = match event.kind.as_str() {
+                            "mic" => "audio:chunk_mic",
+                            "system" => "audio:chunk_sys",
+                            _ => "audio:chunk",
+                        };
+                        let _ = handle.emit(topic, event);
+                        continue;
+                    }
+
+                    let _ = handle.emit("audio:chunk", event.clone());
+                    if let Some(state_ref) = handle.try_state::<Arc<tokio::sync::Mutex<crate::coordinator::Coordinator>>>() {
+                        let coord_state = state_ref.inner().clone();
+                        let meta_for_bg = event.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let coordinator = coord_state.lock().await;
+                            coordinator
+                                .handle_chunk(&meta_for_bg.session_id, &meta_for_bg.path, meta_for_bg.start_ms, meta_for_bg.end_ms)
+                                .await;
+                        });
+                    }
+                }
+                AudioStatusMessage::Level { rms, voiced } => {
+                    let _ = handle.emit("audio:level", AudioLevelEvent { rms, voiced });
+                }
+                AudioStatusMessage::Error(message) => {
+                    let _ = handle.emit("audio:error", message);
+                }
+                AudioStatusMessage::Stopped => {
+                    let _ = handle.emit("audio:stopped", ());
+                }
+                AudioStatusMessage::Paused => {
+                    let _ = handle.emit("audio:paused", ());
+                }
+                AudioStatusMessage::Resumed => {
+                    let _ = handle.emit("audio:resumed", ());
+                }
+                AudioStatusMessage::Levels(levels) => {
+                    let _ = handle.emit("audio:levels", levels);
+                }
+                AudioStatusMessage::Warning(message) => {
+                    let _ = handle.emit("audio:warning", message.clone());
+                    if let Some(state_ref) = handle.try_state::<Arc<tokio::sync::Mutex<crate::notifications::NotificationManager>>>() {
+                        let nm_state = state_ref.inner().clone();
+                        tauri::async_runtime::spawn(async move {
+                            let manager = nm_state.lock().await;
+                            let _ = manager.show_notification("Microphone appears silent", &message).await;
+                        });
+                    }
+                }
+                AudioStatusMessage::Underrun { expected, delivered } => {
+                    if let Some(state_ref) = handle.try_state::<Arc<tokio::sync::Mutex<crate::performance::PerformanceMonitor>>>() {
+                        let perf_state = state_ref.inner().clone();
+                        let handle_for_event = handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let mut monitor = perf_state.lock().await;
+                            if monitor.record_underrun_check(expected, delivered) {
+                                let _ = handle_for_event.emit("audio:underrun", (expected, delivered));
+                            }
+                        });
+                    }
+                }
+                AudioStatusMessage::ChunkWriteTimed(ms) => {
+                    if let Some(state_ref) = handle.try_state::<Arc<tokio::sync::Mutex<crate::performance::PerformanceMonitor>>>() {
+                        let perf_state = state_ref.inner().clone();
+                        tauri::async_runtime::spawn(async move {
+                            let mut monitor = perf_state.lock().await;
+                            monitor.record_chunk_write(ms);
+                        });
+                    }
                 }
+            }
+        }
+    });
+}
+
+/// Spawns the single task that owns the `MultiSourceAudioCapture` and all
+/// session state, and drives the chunk-accumulation loop. Everything else
+/// talks to it only through `control_tx`/`status_rx`.
+fn spawn_audio_actor(sample_rate: u32, chunk_secs: u64) -> (mpsc::Sender<AudioControlMessage>, mpsc::Receiver<AudioStatusMessage>) {
+    let (control_tx, mut control_rx) = mpsc::channel::<AudioControlMessage>(32);
+    let (status_tx, status_rx) = mpsc::channel::<AudioStatusMessage>(256);
+
+    tokio::spawn(async move {
+        let config = MultiAudioConfig {
+            sample_rate,
+            channels: 1,
+            buffer_size: 1024,
+            max_sources: 4,
+            mix_output: true,
+            ..MultiAudioConfig::default()
+        };
+        let capture = Arc::new(MultiSourceAudioCapture::new(config));
+        let data_notify = capture.data_notify();
+
+        let mut app_handle: Option<tauri::AppHandle> = None;
+        let mut chunk_secs = chunk_secs;
+        let mut session_id: Option<String> = None;
+        let mut session_dir: Option<PathBuf> = None;
+        let mut kind: Option<String> = None;
+        let mut active_sources: Vec<String> = Vec::new();
+        let mut manifest: Option<SessionManifest> = None;
+        let mut emit_separate = false;
+        let mut vad_config = VadConfig::default();
+        let mut paused = false;
+        let mut adaptive_chunking = false;
+        let mut chunk_codec = ChunkCodec::default();
+        let mut opus_bitrate = chunk_codec::DEFAULT_OPUS_BITRATE;
+        let mut sample_format = SampleFormat::default();
+        let mut wav_channels: u16 = 1;
+        let mut mic_threshold = MicThresholdConfig::default();
+        // Gain applied to every active source (see `set_source_gain`),
+        // reapplied whenever a source (re)starts so it survives
+        // `SetActiveSources` growing the source set mid-session.
+        let mut input_gain: f32 = 1.0;
+        let mut rms_vad_threshold: Option<f32> = None;
+        let mut rms_vad_hangover_ms: f32 = 500.0;
+        // Consecutive time the mic source has stayed below `mic_threshold`
+        // while recording, and whether this episode has already warned -
+        // reset the moment the mic is loud enough again.
+        let mut mic_silence_ms: f32 = 0.0;
+        let mut mic_silence_warned = false;
+        let mut level_ticker = tokio::time::interval(Duration::from_millis(100));
+
+        // Discontinuity tracking: samples actually pulled from the ring
+        // buffer since `last_underrun_check`, compared each `level_ticker`
+        // tick against what elapsed wall time and `sample_rate` say should
+        // have arrived. `None` means recording hasn't started a check window
+        // yet (or just stopped, which drops the window rather than reporting
+        // a spurious gap).
+        let mut samples_since_check: u64 = 0;
+        let mut last_underrun_check: Option<Instant> = None;
+
+        let mut accumulated: Vec<f32> = Vec::new();
+        let mut chunk_index: u64 = 0;
+        // Exact count of samples already written to chunk files at
+        // `sample_rate`, used to derive sample-accurate `start_ms`/`end_ms`
+        // instead of assuming every chunk is exactly `chunk_secs` long.
+        let mut samples_emitted: u64 = 0;
+        let mut noise_floor: f32 = 0.0;
+        let mut floor_initialized = false;
+
+        // Adaptive-chunking bookkeeping: a separate noise floor so closing
+        // decisions don't interleave with the level-meter's EMA, a cursor of
+        // how much of `accumulated` has already been turned into frames (so
+        // re-notifications don't re-score the same audio), and the running
+        // speech/silence state of the pending chunk.
+        let mut adaptive_noise_floor: f32 = 0.0;
+        let mut adaptive_floor_initialized = false;
+        let mut adaptive_analyzed: usize = 0;
+        let mut pending_voiced_ms: f32 = 0.0;
+        let mut trailing_silence_ms: f32 = 0.0;
+        let mut last_voiced_end: Option<usize> = None;
+
+        loop {
+            let recording = session_id.is_some() && !paused;
+            // Woken by `push_with_policy` as soon as real audio lands in any
+            // source's ring buffer, so the chunker is driven by actual
+            // sample arrivals rather than a fixed wall-clock poll interval.
+            let notified = data_notify.notified();
+
+            tokio::select! {
+                maybe_cmd = control_rx.recv() => {
+                    let Some(cmd) = maybe_cmd else { break };
+                    match cmd {
+                        AudioControlMessage::SetAppHandle(handle) => {
+                            app_handle = Some(handle);
+                        }
+                        AudioControlMessage::GetDevices { reply } => {
+                            let _ = reply.send(capture.discover_sources().await.map_err(|e| e.to_string()));
+                        }
+                        AudioControlMessage::GetSessionInfo { reply } => {
+                            let _ = reply.send(SessionInfo {
+                                session_id: session_id.clone(),
+                                session_dir: session_dir.as_ref().map(|p| p.to_string_lossy().to_string()),
+                            });
+                        }
+                        AudioControlMessage::SetVadConfig { config, reply } => {
+                            vad_config = config;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetMicThreshold { threshold, hold_secs, reply } => {
+                            mic_threshold = MicThresholdConfig { threshold, hold_secs };
+                            mic_silence_ms = 0.0;
+                            mic_silence_warned = false;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetInputSensitivity { gain, reply } => {
+                            input_gain = gain;
+                            for id in &active_sources {
+                                let _ = capture.set_source_gain(id, gain).await;
+                            }
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetVadThreshold { threshold, hangover_ms, reply } => {
+                            rms_vad_threshold = threshold;
+                            rms_vad_hangover_ms = hangover_ms;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::ToggleSeparateEmission { enabled, reply } => {
+                            emit_separate = enabled;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetActiveSources { sources, reply } => {
+                            if session_id.is_none() {
+                                let _ = reply.send(Err("No active session".to_string()));
+                                continue;
+                            }
+                            let to_add: Vec<String> =
+                                sources.iter().filter(|id| !active_sources.contains(id)).cloned().collect();
+                            let to_remove: Vec<String> =
+                                active_sources.iter().filter(|id| !sources.contains(id)).cloned().collect();
 
-                accumulated.append(&mut new_samples);
-                if accumulated.len() >= target_samples {
-                    let chunk: Vec<f32> = accumulated.drain(0..target_samples).collect();
-                    index += 1;
-                    let path = session_dir.join(format!("chunk_{:04}.wav", index as usize));
-                    if let Err(e) = write_wav_chunk(&path, sample_rate, &chunk) { 
-                        eprintln!("Failed to write wav chunk: {}", e);
+                            let mut result = Ok(());
+                            for id in &to_add {
+                                if let Err(e) = capture.add_source(id).await {
+                                    result = Err(e.to_string());
+                                    break;
+                                }
+                                let _ = capture.set_source_gain(id, input_gain).await;
+                            }
+                            for id in &to_remove {
+                                capture.remove_source(id).await;
+                            }
+
+                            if result.is_ok() {
+                                active_sources = sources;
+                                if let (Some(dir), Some(m)) = (&session_dir, &mut manifest) {
+                                    m.active_sources = active_sources.clone();
+                                    let _ = write_session_manifest(dir, m);
+                                }
+                            }
+                            let _ = reply.send(result);
+                        }
+                        AudioControlMessage::ToggleAdaptiveChunking { enabled, reply } => {
+                            adaptive_chunking = enabled;
+                            adaptive_analyzed = 0;
+                            pending_voiced_ms = 0.0;
+                            trailing_silence_ms = 0.0;
+                            last_voiced_end = None;
+                            mic_silence_ms = 0.0;
+                            mic_silence_warned = false;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetChunkFormat { codec, opus_bitrate: bitrate, reply } => {
+                            chunk_codec = codec;
+                            opus_bitrate = bitrate;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetSampleFormat { format, channels, reply } => {
+                            sample_format = format;
+                            wav_channels = channels.max(1);
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::SetChunkSecs { secs, reply } => {
+                            chunk_secs = secs;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::Pause { reply } => {
+                            paused = true;
+                            capture.pause();
+                            let _ = status_tx.send(AudioStatusMessage::Paused).await;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::Resume { reply } => {
+                            paused = false;
+                            capture.resume();
+                            let _ = status_tx.send(AudioStatusMessage::Resumed).await;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::Stop { reply } => {
+                            let _ = capture.stop_recording().await;
+                            session_id = None;
+                            session_dir = None;
+                            kind = None;
+                            active_sources.clear();
+                            manifest = None;
+                            paused = false;
+                            chunk_index = 0;
+                            samples_emitted = 0;
+                            accumulated.clear();
+                            adaptive_analyzed = 0;
+                            pending_voiced_ms = 0.0;
+                            trailing_silence_ms = 0.0;
+                            last_voiced_end = None;
+                            mic_silence_ms = 0.0;
+                            mic_silence_warned = false;
+                            let _ = status_tx.send(AudioStatusMessage::Stopped).await;
+                            let _ = reply.send(Ok(()));
+                        }
+                        AudioControlMessage::StartMic { reply } => {
+                            let result = start_session_sources(
+                                &capture, &app_handle, AudioSourceType::Microphone, "mic",
+                            ).await;
+                            let reply_value = result.map(|started| {
+                                session_id = Some(started.0.clone());
+                                session_dir = Some(started.1.clone());
+                                kind = Some(started.2.clone());
+                                active_sources = started.3.clone();
+                                paused = false;
+                                chunk_index = 0;
+                                samples_emitted = 0;
+                                accumulated.clear();
+                                adaptive_analyzed = 0;
+                                pending_voiced_ms = 0.0;
+                                trailing_silence_ms = 0.0;
+                                last_voiced_end = None;
+                                mic_silence_ms = 0.0;
+                                mic_silence_warned = false;
+                                manifest = Some(new_session_manifest(&started, sample_rate, chunk_secs));
+                                if let Some(m) = &manifest { let _ = write_session_manifest(&started.1, m); }
+                                started.0
+                            });
+                            if reply_value.is_ok() {
+                                for id in &active_sources {
+                                    let _ = capture.set_source_gain(id, input_gain).await;
+                                }
+                            }
+                            let _ = reply.send(reply_value);
+                        }
+                        AudioControlMessage::StartSystem { reply } => {
+                            let result = start_session_sources(
+                                &capture, &app_handle, AudioSourceType::SystemAudio, "system",
+                            ).await;
+                            let reply_value = result.map(|started| {
+                                session_id = Some(started.0.clone());
+                                session_dir = Some(started.1.clone());
+                                kind = Some(started.2.clone());
+                                active_sources = started.3.clone();
+                                paused = false;
+                                chunk_index = 0;
+                                samples_emitted = 0;
+                                accumulated.clear();
+                                adaptive_analyzed = 0;
+                                pending_voiced_ms = 0.0;
+                                trailing_silence_ms = 0.0;
+                                last_voiced_end = None;
+                                mic_silence_ms = 0.0;
+                                mic_silence_warned = false;
+                                manifest = Some(new_session_manifest(&started, sample_rate, chunk_secs));
+                                if let Some(m) = &manifest { let _ = write_session_manifest(&started.1, m); }
+                                started.0
+                            });
+                            if reply_value.is_ok() {
+                                for id in &active_sources {
+                                    let _ = capture.set_source_gain(id, input_gain).await;
+                                }
+                            }
+                            let _ = reply.send(reply_value);
+                        }
+                        AudioControlMessage::StartMix { sources, reply } => {
+                            let result = start_mix_sources(&capture, &app_handle, sources).await;
+                            let reply_value = result.map(|started| {
+                                session_id = Some(started.0.clone());
+                                session_dir = Some(started.1.clone());
+                                kind = Some(started.2.clone());
+                                active_sources = started.3.clone();
+                                paused = false;
+                                chunk_index = 0;
+                                samples_emitted = 0;
+                                accumulated.clear();
+                                adaptive_analyzed = 0;
+                                pending_voiced_ms = 0.0;
+                                trailing_silence_ms = 0.0;
+                                last_voiced_end = None;
+                                mic_silence_ms = 0.0;
+                                mic_silence_warned = false;
+                                manifest = Some(new_session_manifest(&started, sample_rate, chunk_secs));
+                                if let Some(m) = &manifest { let _ = write_session_manifest(&started.1, m); }
+                                started.0
+                            });
+                            if reply_value.is_ok() {
+                                for id in &active_sources {
+                                    let _ = capture.set_source_gain(id, input_gain).await;
+                                }
+                            }
+                            let _ = reply.send(reply_value);
+                        }
+                        AudioControlMessage::ResumeSession { session_id: sid, reply } => {
+                            let result = resume_session(&capture, &app_handle, &sid).await;
+                            let reply_value = result.map(|(dir, loaded)| {
+                                session_id = Some(sid.clone());
+                                session_dir = Some(dir);
+                                kind = Some(loaded.kind.clone());
+                                active_sources = loaded.active_sources.clone();
+                                chunk_secs = loaded.chunk_secs;
+                                paused = false;
+                                chunk_index = loaded.last_chunk_index;
+                                // No exact running sample count survives a crash, so approximate
+                                // it from the manifest assuming prior chunks were `chunk_secs` long -
+                                // the same assumption `ChunkEvent` timestamps used before this fix.
+                                samples_emitted = loaded.last_chunk_index * (chunk_secs as u64) * (sample_rate as u64);
+                                accumulated.clear();
+                                adaptive_analyzed = 0;
+                                pending_voiced_ms = 0.0;
+                                trailing_silence_ms = 0.0;
+                                last_voiced_end = None;
+                                mic_silence_ms = 0.0;
+                                mic_silence_warned = false;
+                                manifest = Some(loaded);
+                                sid
+                            });
+                            if reply_value.is_ok() {
+                                for id in &active_sources {
+                                    let _ = capture.set_source_gain(id, input_gain).await;
+                                }
+                            }
+                            let _ = reply.send(reply_value);
+                        }
+                    }
+                }
+                _ = notified, if recording => {
+                    let src_ids = &active_sources;
+                    let target_samples: usize = (sample_rate as usize) * (chunk_secs as usize);
+
+                    // Pull everything the ring buffer has accumulated since the
+                    // last notification instead of a throttled slice - there's
+                    // no wall-clock poll interval left to pace this against.
+                    let mut new_samples: Vec<f32> = if src_ids.len() > 1 {
+                        capture.get_mixed_audio(None).await
+                    } else {
+                        capture.get_source_audio(&src_ids[0], None).await
+                    };
+
+                    if new_samples.is_empty() {
                         continue;
                     }
-                    if let Some(ref handle) = app {
+
+                    samples_since_check += new_samples.len() as u64;
+
+                    let frames = analyze_vad_frames(&new_samples, sample_rate, &vad_config, &mut noise_floor, &mut floor_initialized);
+                    if let Some(last) = frames.last() {
+                        let _ = status_tx.send(AudioStatusMessage::Level { rms: last.rms, voiced: last.voiced }).await;
+                    }
+
+                    accumulated.append(&mut new_samples);
+
+                    // `ready_chunk` is `Some((chunk, should_write))` once either
+                    // mode decides a chunk boundary has been reached;
+                    // `should_write = false` means the span was pure silence and
+                    // gets dropped rather than written to disk.
+                    let ready_chunk: Option<(Vec<f32>, bool)> = if adaptive_chunking {
+                        let frame_size = vad_frame_size(sample_rate, &vad_config);
+                        if frame_size == 0 {
+                            None
+                        } else {
+                            // Only score the tail that hasn't been turned into
+                            // frames yet, so a frame's effect on the noise floor
+                            // and the speech/silence run isn't counted twice
+                            // across repeated notifications before it flushes.
+                            let base_offset = adaptive_analyzed;
+                            let new_frames = analyze_vad_frames(
+                                &accumulated[adaptive_analyzed..],
+                                sample_rate,
+                                &vad_config,
+                                &mut adaptive_noise_floor,
+                                &mut adaptive_floor_initialized,
+                            );
+                            for (i, frame) in new_frames.iter().enumerate() {
+                                if frame.voiced {
+                                    pending_voiced_ms += vad_config.frame_ms;
+                                    trailing_silence_ms = 0.0;
+                                    last_voiced_end = Some(base_offset + (i + 1) * frame_size);
+                                } else {
+                                    trailing_silence_ms += vad_config.frame_ms;
+                                }
+                            }
+                            adaptive_analyzed += new_frames.len() * frame_size;
+
+                            let max_samples = ((vad_config.max_chunk_secs * sample_rate as f32) as usize).max(frame_size);
+                            let flush = if accumulated.len() >= max_samples {
+                                // A continuous talker still gets split at the cap,
+                                // even mid-utterance.
+                                Some((max_samples, pending_voiced_ms > 0.0))
+                            } else {
+                                last_voiced_end
+                                    .filter(|_| {
+                                        trailing_silence_ms >= vad_config.silence_hold_ms
+                                            && pending_voiced_ms >= vad_config.min_chunk_secs * 1000.0
+                                    })
+                                    .map(|cut| (cut, true))
+                            };
+
+                            flush.map(|(cut, has_speech)| {
+                                let chunk: Vec<f32> = accumulated.drain(0..cut).collect();
+                                // On a non-max-cap flush `cut` (`last_voiced_end`) is
+                                // usually behind `adaptive_analyzed` - analysis keeps
+                                // advancing through trailing silence right up until
+                                // `silence_hold_ms` triggers the flush - so the drain
+                                // leaves already-scored samples at the front of the
+                                // buffer. Shift `adaptive_analyzed` down by the same
+                                // `cut` instead of zeroing it, so those samples aren't
+                                // fed through `analyze_vad_frames` a second time.
+                                adaptive_analyzed = adaptive_analyzed.saturating_sub(cut);
+                                pending_voiced_ms = 0.0;
+                                trailing_silence_ms = 0.0;
+                                last_voiced_end = None;
+                                mic_silence_ms = 0.0;
+                                mic_silence_warned = false;
+                                (chunk, has_speech)
+                            })
+                        }
+                    } else if accumulated.len() >= target_samples {
+                        let chunk: Vec<f32> = accumulated.drain(0..target_samples).collect();
+                        let chunk_frames = analyze_vad_frames(&chunk, sample_rate, &vad_config, &mut noise_floor, &mut floor_initialized);
+                        // Raw-level gate (`ac_set_vad_threshold`), independent of the
+                        // band-ratio VAD above: a chunk whose frames are *all* quieter
+                        // than `rms_vad_threshold` for at least `rms_vad_hangover_ms`
+                        // gets dropped even when its voiced-frame ratio alone would
+                        // have kept it.
+                        let below_threshold = rms_vad_threshold.is_some_and(|threshold| {
+                            !chunk_frames.is_empty()
+                                && chunk_frames.iter().all(|f| f.rms < threshold)
+                                && chunk_frames.len() as f32 * vad_config.frame_ms >= rms_vad_hangover_ms
+                        });
+                        let should_write = !below_threshold && (chunk_frames.is_empty() || {
+                            let voiced_ratio = chunk_frames.iter().filter(|f| f.voiced).count() as f32 / chunk_frames.len() as f32;
+                            voiced_ratio >= vad_config.min_voiced_chunk_ratio
+                        });
+                        Some((chunk, should_write))
+                    } else {
+                        None
+                    };
+
+                    if let Some((chunk, should_write)) = ready_chunk {
+                        if !should_write {
+                            continue;
+                        }
+
+                        chunk_index += 1;
+                        let Some(dir) = session_dir.clone() else { continue };
+                        let write_start = Instant::now();
+                        let (path, actual_codec) = match write_chunk_file(
+                            &dir,
+                            &format!("chunk_{:04}", chunk_index as usize),
+                            chunk_codec,
+                            opus_bitrate,
+                            sample_format,
+                            wav_channels,
+                            sample_rate,
+                            &chunk,
+                        ) {
+                            Ok(result) => result,
+                            Err(e) => {
+                                let _ = status_tx.send(AudioStatusMessage::Error(format!("Failed to write {:?} chunk: {}", chunk_codec, e))).await;
+                                continue;
+                            }
+                        };
+                        let write_ms = write_start.elapsed().as_secs_f64() * 1000.0;
+                        let _ = status_tx.send(AudioStatusMessage::ChunkWriteTimed(write_ms)).await;
+                        if let Some(m) = manifest.as_mut() {
+                            m.last_chunk_index = chunk_index;
+                            let _ = write_session_manifest(&dir, m);
+                        }
+
+                        // Derived from the running sample counter, not the chunk
+                        // index, so timestamps stay exact even if a chunk ends up
+                        // shorter or longer than `chunk_secs` (e.g. an adaptive
+                        // cut). Chunks dropped by the VAD-skip path above are not
+                        // counted, so timestamps follow the written chunks laid
+                        // end to end, not wall-clock session time.
+                        let start_ms = (samples_emitted as u128) * 1000 / (sample_rate as u128);
+                        samples_emitted += chunk.len() as u64;
+                        let end_ms = (samples_emitted as u128) * 1000 / (sample_rate as u128);
+
                         let meta = ChunkEvent {
-                            session_id: session_id_clone.clone(),
-                            index,
+                            session_id: session_id.clone().unwrap_or_default(),
+                            index: chunk_index,
                             path: path.to_string_lossy().to_string(),
-                            start_ms: (index as u128 - 1) * (chunk_secs as u128) * 1000,
-                            end_ms: (index as u128) * (chunk_secs as u128) * 1000,
-                            duration_ms: (chunk_secs as u128) * 1000,
+                            start_ms,
+                            end_ms,
+                            duration_ms: end_ms - start_ms,
                             bytes: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
-                            kind: kind_string.clone(),
+                            kind: kind.clone().unwrap_or_default(),
+                            separate_track: false,
+                            codec: actual_codec.extension().to_string(),
+                            sample_format: sample_format.label().to_string(),
+                            bits_per_sample: sample_format.bits_per_sample(),
+                            opus_bitrate: (actual_codec == ChunkCodec::Opus).then_some(opus_bitrate),
                         };
-                        // Emit to frontend
-                        let _ = handle.emit("audio:chunk", meta.clone());
-                        // Also forward to coordinator server-side
-                        if let Some(state_ref) = handle.try_state::<std::sync::Arc<tokio::sync::Mutex<crate::coordinator::Coordinator>>>() {
-                            let coord_state = state_ref.inner().clone();
-                            let meta_for_bg = meta.clone();
-                            tauri::async_runtime::spawn(async move {
-                                let coordinator = coord_state.lock().await;
-                                coordinator.handle_chunk(&meta_for_bg.session_id, &meta_for_bg.path, meta_for_bg.start_ms, meta_for_bg.end_ms).await;
-                            });
-                        }
+                        let _ = status_tx.send(AudioStatusMessage::ChunkReady(meta.clone())).await;
 
-                        // Optionally emit separate mic/system chunks if available
-                        if emit_separate_flag && src_ids.len() > 1 {
+                        if emit_separate && src_ids.len() > 1 {
                             let mic_buf = capture.get_source_audio(&src_ids[0], Some(target_samples / 5)).await;
                             let sys_buf = capture.get_source_audio(&src_ids[1], Some(target_samples / 5)).await;
                             if !mic_buf.is_empty() {
-                                let mic_path = session_dir.join(format!("chunk_{:04}_mic.wav", index as usize));
-                                let _ = write_wav_chunk(&mic_path, sample_rate, &mic_buf);
-                                let mut m = ChunkEvent { ..meta.clone() };
-                                m.path = mic_path.to_string_lossy().to_string();
-                                m.kind = "mic".to_string();
-                                let _ = handle.emit("audio:chunk_mic", m);
+                                if let Ok((mic_path, mic_codec)) = write_chunk_file(&dir, &format!("chunk_{:04}_mic", chunk_index as usize), chunk_codec, opus_bitrate, sample_format, wav_channels, sample_rate, &mic_buf) {
+                                    let mut m = meta.clone();
+                                    m.path = mic_path.to_string_lossy().to_string();
+                                    m.kind = "mic".to_string();
+                                    m.separate_track = true;
+                                    m.codec = mic_codec.extension().to_string();
+                                    m.opus_bitrate = (mic_codec == ChunkCodec::Opus).then_some(opus_bitrate);
+                                    let _ = status_tx.send(AudioStatusMessage::ChunkReady(m)).await;
+                                }
                             }
                             if !sys_buf.is_empty() {
-                                let sys_path = session_dir.join(format!("chunk_{:04}_sys.wav", index as usize));
-                                let _ = write_wav_chunk(&sys_path, sample_rate, &sys_buf);
-                                let mut m = ChunkEvent { ..meta.clone() };
-                                m.path = sys_path.to_string_lossy().to_string();
-                                m.kind = "system".to_string();
-                                let _ = handle.emit("audio:chunk_sys", m);
+                                if let Ok((sys_path, sys_codec)) = write_chunk_file(&dir, &format!("chunk_{:04}_sys", chunk_index as usize), chunk_codec, opus_bitrate, sample_format, wav_channels, sample_rate, &sys_buf) {
+                                    let mut m = meta.clone();
+                                    m.path = sys_path.to_string_lossy().to_string();
+                                    m.kind = "system".to_string();
+                                    m.separate_track = true;
+                                    m.codec = sys_codec.extension().to_string();
+                                    m.opus_bitrate = (sys_codec == ChunkCodec::Opus).then_some(opus_bitrate);
+                                    let _ = status_tx.send(AudioStatusMessage::ChunkReady(m)).await;
+                                }
+                            }
+                        }
+                    }
+                }
+                _ = level_ticker.tick() => {
+                    if session_id.is_none() {
+                        continue;
+                    }
+
+                    if recording {
+                        let now = Instant::now();
+                        if let Some(last) = last_underrun_check {
+                            let elapsed_secs = now.duration_since(last).as_secs_f64();
+                            let expected = (elapsed_secs * sample_rate as f64) as u64;
+                            let _ = status_tx
+                                .send(AudioStatusMessage::Underrun { expected, delivered: samples_since_check })
+                                .await;
+                            samples_since_check = 0;
+                        }
+                        last_underrun_check = Some(now);
+                    } else {
+                        last_underrun_check = None;
+                        samples_since_check = 0;
+                    }
+
+                    let levels = capture.get_active_source_levels().await;
+                    if !levels.is_empty() {
+                        let events: Vec<SourceLevelEvent> = levels
+                            .iter()
+                            .map(|(source_id, kind, rms, peak)| SourceLevelEvent {
+                                source_id: source_id.clone(),
+                                kind: kind.to_string(),
+                                peak: *peak,
+                                rms: *rms,
+                            })
+                            .collect();
+                        let _ = status_tx.send(AudioStatusMessage::Levels(events)).await;
+                    }
+
+                    // Only gate on the mic while actually recording (not
+                    // paused) - a paused mic legitimately reads silent.
+                    if recording {
+                        if let Some((_, _, mic_rms, _)) = levels.iter().find(|(_, kind, _, _)| *kind == "mic") {
+                            if *mic_rms < mic_threshold.threshold {
+                                mic_silence_ms += 100.0;
+                                if !mic_silence_warned && mic_silence_ms >= mic_threshold.hold_secs * 1000.0 {
+                                    mic_silence_warned = true;
+                                    let _ = status_tx
+                                        .send(AudioStatusMessage::Warning("Microphone appears silent".to_string()))
+                                        .await;
+                                }
+                            } else {
+                                mic_silence_ms = 0.0;
+                                mic_silence_warned = false;
                             }
                         }
                     }
                 }
             }
-        });
+        }
+    });
+
+    (control_tx, status_rx)
+}
 
-        self.session_id = Some(session_id.clone());
-        self.session_dir = Some(dir);
-        self.active_sources = source_ids;
-        self.kind = Some(kind.to_string());
+async fn start_session_sources(
+    capture: &MultiSourceAudioCapture,
+    app_handle: &Option<tauri::AppHandle>,
+    device_type: AudioSourceType,
+    kind: &str,
+) -> Result<(String, PathBuf, String, Vec<String>), String> {
+    let sources = capture.discover_sources().await.map_err(|e| e.to_string())?;
+    let source = sources
+        .into_iter()
+        .find(|s| s.device_type == device_type)
+        .ok_or_else(|| format!("No {} source found", kind))?;
+    begin_session(capture, app_handle, vec![source.id], kind).await
+}
 
-        Ok(session_id)
+async fn start_mix_sources(
+    capture: &MultiSourceAudioCapture,
+    app_handle: &Option<tauri::AppHandle>,
+    sources: Option<Vec<String>>,
+) -> Result<(String, PathBuf, String, Vec<String>), String> {
+    let ids = match sources {
+        Some(ids) => ids,
+        None => {
+            let discovered = capture.discover_sources().await.map_err(|e| e.to_string())?;
+            let mut ids = Vec::new();
+            if let Some(mic) = discovered.iter().find(|s| s.device_type == AudioSourceType::Microphone) {
+                ids.push(mic.id.clone());
+            }
+            if let Some(sys) = discovered.iter().find(|s| s.device_type == AudioSourceType::SystemAudio) {
+                ids.push(sys.id.clone());
+            }
+            ids
+        }
+    };
+    if ids.is_empty() {
+        return Err("No available sources for mix".to_string());
     }
+    begin_session(capture, app_handle, ids, "mix").await
+}
+
+async fn begin_session(
+    capture: &MultiSourceAudioCapture,
+    app_handle: &Option<tauri::AppHandle>,
+    source_ids: Vec<String>,
+    kind: &str,
+) -> Result<(String, PathBuf, String, Vec<String>), String> {
+    let _ = capture.stop_recording().await;
 
-    fn resolve_session_dir(&self, session_id: &str) -> Result<PathBuf> {
-        let handle = self.app_handle.as_ref().ok_or_else(|| anyhow!("App handle not set"))?;
-        let base = handle
-            .path()
-            .app_data_dir()
-            .map_err(|e| anyhow!("Failed to get app_data_dir: {}", e))?;
-        Ok(base.join("recordings").join(session_id))
+    let session_id = Uuid::new_v4().to_string();
+    let dir = resolve_session_dir(app_handle, &session_id).map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    capture
+        .start_multi_recording(source_ids.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok((session_id, dir, kind.to_string(), source_ids))
+}
+
+fn resolve_session_dir(app_handle: &Option<tauri::AppHandle>, session_id: &str) -> Result<PathBuf> {
+    let handle = app_handle.as_ref().ok_or_else(|| anyhow!("App handle not set"))?;
+    let base = handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| anyhow!("Failed to get app_data_dir: {}", e))?;
+    Ok(base.join("recordings").join(session_id))
+}
+
+fn new_session_manifest(
+    started: &(String, PathBuf, String, Vec<String>),
+    sample_rate: u32,
+    chunk_secs: u64,
+) -> SessionManifest {
+    SessionManifest {
+        session_id: started.0.clone(),
+        kind: started.2.clone(),
+        sample_rate,
+        chunk_secs,
+        active_sources: started.3.clone(),
+        last_chunk_index: 0,
+        started_at_ms: chrono::Utc::now().timestamp_millis() as u128,
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct SessionInfo {
-    pub session_id: Option<String>,
-    pub session_dir: Option<String>,
+fn write_session_manifest(dir: &PathBuf, manifest: &SessionManifest) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(manifest).unwrap_or_default();
+    std::fs::write(dir.join("session.json"), json)
+}
+
+fn read_session_manifest(dir: &PathBuf) -> Option<SessionManifest> {
+    let content = std::fs::read_to_string(dir.join("session.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Re-attaches capture to a session left behind by a crash: looks up its
+/// manifest for the original sources/parameters and restarts recording on
+/// the same device ids, so the actor can resume chunk numbering from
+/// `last_chunk_index` instead of starting a fresh session.
+async fn resume_session(
+    capture: &MultiSourceAudioCapture,
+    app_handle: &Option<tauri::AppHandle>,
+    session_id: &str,
+) -> Result<(PathBuf, SessionManifest), String> {
+    let dir = resolve_session_dir(app_handle, session_id).map_err(|e| e.to_string())?;
+    let manifest = read_session_manifest(&dir)
+        .ok_or_else(|| format!("No session.json manifest found for session {}", session_id))?;
+
+    let _ = capture.stop_recording().await;
+    capture
+        .start_multi_recording(manifest.active_sources.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok((dir, manifest))
+}
+
+/// Scans the `recordings` directory for sessions that have a manifest but no
+/// `final.wav`, i.e. were interrupted before `Coordinator::post_process` ran.
+fn scan_recoverable_sessions(recordings_dir: &PathBuf) -> Vec<RecoverableSession> {
+    let mut result = Vec::new();
+    let Ok(entries) = std::fs::read_dir(recordings_dir) else { return result };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_dir() || path.join("final.wav").exists() {
+            continue;
+        }
+        let Some(manifest) = read_session_manifest(&path) else { continue };
+        result.push(RecoverableSession {
+            session_id: manifest.session_id.clone(),
+            session_dir: path.to_string_lossy().to_string(),
+            manifest,
+        });
+    }
+    result
+}
+
+/// Builds `dir/{stem}.{ext}` for `codec` and writes the chunk there,
+/// returning the path actually written (which differs from the requested
+/// one if Opus encoding failed and `write_chunk` fell back to WAV) and the
+/// codec that ended up on disk.
+fn write_chunk_file(
+    dir: &std::path::Path,
+    stem: &str,
+    codec: ChunkCodec,
+    opus_bitrate: i32,
+    sample_format: SampleFormat,
+    channels: u16,
+    sample_rate: u32,
+    data: &[f32],
+) -> Result<(PathBuf, ChunkCodec)> {
+    let path = dir.join(format!("{}.{}", stem, codec.extension()));
+    let actual_codec = write_chunk(codec, opus_bitrate, sample_format, channels, &path, sample_rate, data)?;
+    let actual_path = if actual_codec == codec { path } else { path.with_extension(actual_codec.extension()) };
+    Ok((actual_path, actual_codec))
+}
+
+/// Writes a chunk in `codec`'s format, dispatching to the matching encoder.
+/// `opus_bitrate` is only used when `codec` is `ChunkCodec::Opus`. A dropped
+/// chunk of meeting audio is worse than a larger one, so an Opus encode
+/// failure (e.g. the encoder can't be allocated) falls back to WAV instead
+/// of propagating the error; the codec actually written is returned so
+/// callers can reflect it in `ChunkEvent`/the on-disk extension.
+fn write_chunk(
+    codec: ChunkCodec,
+    opus_bitrate: i32,
+    sample_format: SampleFormat,
+    channels: u16,
+    path: &PathBuf,
+    sample_rate: u32,
+    data: &[f32],
+) -> Result<ChunkCodec> {
+    match codec {
+        ChunkCodec::Wav => write_wav_chunk(path, sample_rate, channels, sample_format, data).map(|_| ChunkCodec::Wav),
+        // The actor's chunk data is always mono; `channels` only sets the
+        // WAV writer's layout, so the Opus encoder is told the data's own count.
+        ChunkCodec::Opus => match chunk_codec::write_opus_chunk(path, sample_rate, 1, data, opus_bitrate) {
+            Ok(()) => Ok(ChunkCodec::Opus),
+            Err(e) => {
+                eprintln!("⚠️ Opus encode failed ({}), falling back to WAV for {}", e, path.display());
+                let wav_path = path.with_extension(ChunkCodec::Wav.extension());
+                write_wav_chunk(&wav_path, sample_rate, channels, sample_format, data)?;
+                Ok(ChunkCodec::Wav)
+            }
+        },
+        ChunkCodec::Flac => chunk_codec::write_flac_chunk(path, sample_rate, data).map(|_| ChunkCodec::Flac),
+    }
+}
+
+fn write_wav_chunk(path: &PathBuf, sample_rate: u32, channels: u16, format: SampleFormat, data: &[f32]) -> Result<()> {
+    if let Some(parent) = path.parent() { std::fs::create_dir_all(parent)?; }
+    let spec = hound::WavSpec {
+        channels: channels.max(1),
+        sample_rate,
+        bits_per_sample: format.bits_per_sample(),
+        sample_format: format.hound_sample_format(),
+    };
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    match format {
+        SampleFormat::Int16 => {
+            for &sample in data {
+                let s = (sample.max(-1.0).min(1.0) * i16::MAX as f32) as i16;
+                writer.write_sample(s)?;
+            }
+        }
+        SampleFormat::Int24 => {
+            // Hound stores 24-bit samples in a 32-bit container scaled to
+            // the 24-bit range, not the full i32 range.
+            const MAX_24BIT: f32 = 8_388_607.0;
+            for &sample in data {
+                let s = (sample.max(-1.0).min(1.0) * MAX_24BIT) as i32;
+                writer.write_sample(s)?;
+            }
+        }
+        SampleFormat::Float32 => {
+            // Native float - no clamp-and-quantize, so headroom above/below
+            // [-1, 1] (e.g. from a hot mic) survives into the chunk file.
+            for &sample in data {
+                writer.write_sample(sample)?;
+            }
+        }
+    }
+    writer.finalize()?;
+    Ok(())
 }
 
 #[tauri::command]
 pub async fn ac_get_active_session_info(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<SessionInfo, String> {
     let chunker = state.lock().await;
-    Ok(SessionInfo {
-        session_id: chunker.session_id.clone(),
-        session_dir: chunker.session_dir.as_ref().map(|p| p.to_string_lossy().to_string()),
-    })
+    Ok(chunker.get_session_info().await)
 }
 
 #[tauri::command]
@@ -242,41 +1429,159 @@ pub async fn ac_stop_and_finalize(
     // Grab session dir before stopping
     let session_dir = {
         let chunker = state.lock().await;
-        chunker.session_dir.clone()
+        chunker.get_session_info().await.session_dir
     };
     {
-        let mut chunker = state.lock().await;
+        let chunker = state.lock().await;
         let _ = chunker.stop_all().await;
     }
     if let Some(dir) = session_dir {
         let coordinator = coord_state.lock().await;
-        coordinator.post_process(&dir.to_string_lossy()).await;
+        coordinator.post_process(&dir).await;
     }
     Ok(())
 }
 
 #[tauri::command]
 pub async fn ac_toggle_separate_emission(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>, enabled: bool) -> Result<(), String> {
-    let mut chunker = state.lock().await;
-    chunker.emit_separate = enabled;
-    Ok(())
+    let chunker = state.lock().await;
+    chunker.toggle_separate_emission(enabled).await.map_err(|e| e.to_string())
 }
 
-fn write_wav_chunk(path: &PathBuf, sample_rate: u32, data: &[f32]) -> Result<()> {
-    if let Some(parent) = path.parent() { std::fs::create_dir_all(parent)?; }
-    let spec = hound::WavSpec {
-        channels: 1,
-        sample_rate,
-        bits_per_sample: 16,
-        sample_format: hound::SampleFormat::Int,
+/// Toggles adaptive (VAD-closed) chunk boundaries on or off for the active
+/// session; when off, chunks keep cutting on the fixed `chunk_secs` timer.
+#[tauri::command]
+pub async fn ac_toggle_adaptive_chunking(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>, enabled: bool) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.toggle_adaptive_chunking(enabled).await.map_err(|e| e.to_string())
+}
+
+/// Sets the codec chunk files are written in. `codec` is `"wav"`, `"opus"`,
+/// or `"flac"`; `opus_bitrate` (bits/sec) is only used for `"opus"` and
+/// defaults to `chunk_codec::DEFAULT_OPUS_BITRATE` when omitted.
+#[tauri::command]
+pub async fn ac_set_chunk_format(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    codec: String,
+    opus_bitrate: Option<i32>,
+) -> Result<(), String> {
+    let codec = match codec.to_lowercase().as_str() {
+        "wav" => ChunkCodec::Wav,
+        "opus" => ChunkCodec::Opus,
+        "flac" => ChunkCodec::Flac,
+        other => return Err(format!("unsupported chunk codec '{}'", other)),
     };
-    let mut writer = hound::WavWriter::create(path, spec)?;
-    for &sample in data {
-        let s = (sample.max(-1.0).min(1.0) * i16::MAX as f32) as i16;
-        writer.write_sample(s)?;
-    }
-    writer.finalize()?;
-    Ok(())
+    let chunker = state.lock().await;
+    chunker
+        .set_chunk_format(codec, opus_bitrate.unwrap_or(chunk_codec::DEFAULT_OPUS_BITRATE))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Sets the PCM sample format/channel count `write_wav_chunk` writes in;
+/// only takes effect while `ChunkCodec::Wav` is selected. `format` is
+/// `"i16"`, `"i24"`, or `"f32"`; `channels` defaults to 1 (mono) when
+/// omitted.
+#[tauri::command]
+pub async fn ac_set_sample_format(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    format: String,
+    channels: Option<u16>,
+) -> Result<(), String> {
+    let format = match format.to_lowercase().as_str() {
+        "i16" => SampleFormat::Int16,
+        "i24" => SampleFormat::Int24,
+        "f32" => SampleFormat::Float32,
+        other => return Err(format!("unsupported sample format '{}'", other)),
+    };
+    let chunker = state.lock().await;
+    chunker
+        .set_sample_format(format, channels.unwrap_or(1))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn ac_pause(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.pause().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn ac_resume(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.resume().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn ac_set_chunk_secs(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>, secs: u64) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.set_chunk_secs(secs).await.map_err(|e| e.to_string())
+}
+
+/// Changes which sources the active session records from, live - sources
+/// newly listed are started, sources dropped from `sources` stop being
+/// mixed in. Errors if no session is open.
+#[tauri::command]
+pub async fn ac_set_active_sources(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    sources: Vec<String>,
+) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.set_active_sources(sources).await.map_err(|e| e.to_string())
+}
+
+/// Tunes the dead-mic gate: `threshold` is an RMS level in `[0, 1]`,
+/// `hold_secs` how long the mic source must stay below it (while recording)
+/// before a "Microphone appears silent" warning fires.
+#[tauri::command]
+pub async fn ac_set_mic_threshold(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    threshold: f32,
+    hold_secs: f32,
+) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.set_mic_threshold(threshold, hold_secs).await.map_err(|e| e.to_string())
+}
+
+/// Scales captured samples before mixing/writing - effectively an input
+/// sensitivity/gain knob driven by the level meter UI.
+#[tauri::command]
+pub async fn ac_set_input_sensitivity(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    gain: f32,
+) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.set_input_sensitivity(gain).await.map_err(|e| e.to_string())
+}
+
+/// Sets the RMS silence gate that drops a fixed-interval chunk (so no
+/// `audio:chunk` is emitted for it) when every frame of it is below
+/// `threshold` and the chunk lasts at least `hangover_ms`; a silent stretch
+/// inside an otherwise louder chunk is kept. Pass `threshold: None` to
+/// disable the gate.
+#[tauri::command]
+pub async fn ac_set_vad_threshold(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    threshold: Option<f32>,
+    hangover_ms: f32,
+) -> Result<(), String> {
+    let chunker = state.lock().await;
+    chunker.set_vad_threshold(threshold, hangover_ms).await.map_err(|e| e.to_string())
+}
+
+/// Capture-side performance metrics: real process memory/CPU plus the
+/// discontinuity counters (`samples_dropped`, `underrun_events`,
+/// `avg_chunk_write_ms`) the actor feeds via `AudioStatusMessage::Underrun`/
+/// `ChunkWriteTimed`. Distinct from the app-wide `get_performance_metrics`
+/// command only in which subsystem asked for a refresh first.
+#[tauri::command]
+pub async fn ac_get_performance_metrics(
+    perf_monitor: tauri::State<'_, Arc<tokio::sync::Mutex<crate::performance::PerformanceMonitor>>>,
+) -> Result<crate::performance::PerformanceMetrics, String> {
+    let mut monitor = perf_monitor.lock().await;
+    monitor.update_system_metrics().await;
+    Ok(monitor.get_metrics().clone())
 }
 
 #[tauri::command]
@@ -287,26 +1592,65 @@ pub async fn ac_get_devices(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -
 
 #[tauri::command]
 pub async fn ac_start_mic(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<String, String> {
-    let mut chunker = state.lock().await;
+    let chunker = state.lock().await;
     chunker.start_mic().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub async fn ac_start_system(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<String, String> {
-    let mut chunker = state.lock().await;
+    let chunker = state.lock().await;
     chunker.start_system().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub async fn ac_start_mix(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<String, String> {
-    let mut chunker = state.lock().await;
+    let chunker = state.lock().await;
     chunker.start_mix().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub async fn ac_stop_all(state: tauri::State<'_, Arc<Mutex<AudioChunker>>>) -> Result<(), String> {
-    let mut chunker = state.lock().await;
+    let chunker = state.lock().await;
     chunker.stop_all().await.map_err(|e| e.to_string())
 }
 
+/// Lists session directories left behind by a crash or forced quit: they
+/// have a `session.json` manifest (written at session start) but no
+/// `final.wav` (written by `Coordinator::post_process`), so the frontend can
+/// offer to resume or finalize them on launch.
+#[tauri::command]
+pub async fn ac_list_recoverable(app: tauri::AppHandle) -> Result<Vec<RecoverableSession>, String> {
+    let base = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app_data_dir: {}", e))?;
+    Ok(scan_recoverable_sessions(&base.join("recordings")))
+}
+
+#[tauri::command]
+pub async fn ac_resume_session(
+    state: tauri::State<'_, Arc<Mutex<AudioChunker>>>,
+    session_id: String,
+) -> Result<String, String> {
+    let chunker = state.lock().await;
+    chunker.resume_session(session_id).await.map_err(|e| e.to_string())
+}
 
+/// Finalizes an orphaned session directly, without resuming live capture:
+/// runs the same `Coordinator::post_process` pass that a normal
+/// `ac_stop_and_finalize` would, producing `final.wav`/`.jsonl`/`.txt`/`.srt`.
+#[tauri::command]
+pub async fn ac_finalize_session(
+    app: tauri::AppHandle,
+    coord_state: tauri::State<'_, Arc<tokio::sync::Mutex<crate::coordinator::Coordinator>>>,
+    session_id: String,
+) -> Result<(), String> {
+    let base = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app_data_dir: {}", e))?;
+    let dir = base.join("recordings").join(&session_id).to_string_lossy().to_string();
+    let coordinator = coord_state.lock().await;
+    coordinator.post_process(&dir).await;
+    Ok(())
+}
@@ -7,14 +7,14 @@ mod tests {
     #[tokio::test]
     async fn test_audio_device_enumeration() {
         let capture = AudioCapture::new().unwrap();
-        let devices = capture.enumerate_devices().unwrap();
+        let devices = capture.enumerate_devices().await.unwrap();
         assert!(!devices.is_empty(), "Should find at least one audio device");
     }
 
     #[tokio::test]
     async fn test_system_audio_device() {
         let capture = AudioCapture::new().unwrap();
-        let devices = capture.enumerate_devices().unwrap();
+        let devices = capture.enumerate_devices().await.unwrap();
         let system_device = devices.iter().find(|d| d.is_system);
         assert!(system_device.is_some(), "Should have system audio device");
     }
@@ -0,0 +1,67 @@
+//! Converts `WhisperManager::transcribe_segmented` output into the
+//! subtitle/transcript formats external tools expect - SRT and WebVTT cue
+//! lists, or an OpenAI-style verbose JSON payload with per-segment timings.
+
+use crate::whisper::{format_srt_timestamp, format_vtt_timestamp, Segment};
+
+/// Output format for `WhisperManager::transcribe_to_format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    Srt,
+    Vtt,
+    VerboseJson,
+}
+
+/// Renders `segments` as a SubRip (`.srt`) cue list.
+pub fn to_srt(segments: &[Segment]) -> String {
+    let mut out = String::new();
+    for (i, segment) in segments.iter().enumerate() {
+        out.push_str(&format!("{}\n", i + 1));
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_srt_timestamp(segment.start_sec as f64),
+            format_srt_timestamp(segment.end_sec as f64)
+        ));
+        out.push_str(segment.text.trim());
+        out.push_str("\n\n");
+    }
+    out
+}
+
+/// Renders `segments` as a WebVTT (`.vtt`) cue list.
+pub fn to_vtt(segments: &[Segment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for segment in segments {
+        out.push_str(&format!(
+            "{} --> {}\n",
+            format_vtt_timestamp(segment.start_sec as f64),
+            format_vtt_timestamp(segment.end_sec as f64)
+        ));
+        out.push_str(segment.text.trim());
+        out.push_str("\n\n");
+    }
+    out
+}
+
+/// Renders `segments` as an OpenAI-style `verbose_json` transcription
+/// response: the joined full text plus one timed entry per segment.
+pub fn to_verbose_json(segments: &[Segment]) -> serde_json::Value {
+    let text = segments
+        .iter()
+        .map(|s| s.text.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let duration = segments.last().map(|s| s.end_sec).unwrap_or(0.0);
+
+    serde_json::json!({
+        "task": "transcribe",
+        "duration": duration,
+        "text": text,
+        "segments": segments.iter().enumerate().map(|(i, s)| serde_json::json!({
+            "id": i,
+            "start": s.start_sec,
+            "end": s.end_sec,
+            "text": s.text,
+        })).collect::<Vec<_>>(),
+    })
+}